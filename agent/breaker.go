@@ -0,0 +1,151 @@
+package agent
+
+import (
+	"context"
+	"errors"
+	"log/slog"
+	"sync"
+	"time"
+
+	"github.com/nevindra/oasis/core"
+	"github.com/nevindra/oasis/provider"
+)
+
+// ErrCircuitOpen is returned by a provider wrapped with
+// CircuitBreakerMiddleware while its circuit is open.
+var ErrCircuitOpen = errors.New("provider circuit breaker open")
+
+// BreakerOption configures CircuitBreakerMiddleware.
+type BreakerOption func(*breakerProvider)
+
+// BreakerLogger sets the logger for circuit state changes: opening logs at
+// WARN, half-open probes and closing at INFO. If not set, nothing is logged.
+func BreakerLogger(l *slog.Logger) BreakerOption {
+	return func(b *breakerProvider) { b.logger = l }
+}
+
+// CircuitBreakerMiddleware returns a provider.Middleware that stops calling
+// a provider during a sustained outage. After threshold consecutive failed
+// calls the circuit opens and calls fail immediately with ErrCircuitOpen for
+// cooldown. Then a single call is let through as a probe: success closes the
+// circuit, failure re-opens it for another cooldown.
+//
+// Server errors, timeouts, and transport failures count as failures. Client
+// errors (HTTP 4xx other than 408 and 429) and calls the caller cancelled do
+// not, since they say nothing about the provider's health. Place it outside
+// RetryMiddleware so an open circuit also skips the retry backoff:
+//
+//	p := provider.Chain(
+//	    agent.CircuitBreakerMiddleware(5, 30*time.Second),
+//	    agent.RetryMiddleware(),
+//	)(base)
+//
+// Panics if threshold <= 0.
+func CircuitBreakerMiddleware(threshold int, cooldown time.Duration, opts ...BreakerOption) provider.Middleware {
+	if threshold <= 0 {
+		panic("agent.CircuitBreakerMiddleware: threshold must be > 0")
+	}
+	return func(p core.Provider) core.Provider {
+		b := &breakerProvider{inner: p, threshold: threshold, cooldown: cooldown}
+		for _, opt := range opts {
+			opt(b)
+		}
+		if b.logger == nil {
+			b.logger = nopLogger
+		}
+		return b
+	}
+}
+
+// breakerProvider is the Provider returned by CircuitBreakerMiddleware.
+type breakerProvider struct {
+	inner     core.Provider
+	threshold int
+	cooldown  time.Duration
+	logger    *slog.Logger
+
+	mu       sync.Mutex
+	fails    int // consecutive failures
+	openedAt time.Time
+	probing  bool // a half-open probe is in flight
+}
+
+// Name delegates to the inner provider.
+func (b *breakerProvider) Name() string { return b.inner.Name() }
+
+// ChatStream implements Provider. While the circuit is open it returns
+// ErrCircuitOpen without calling the inner provider. ch is always closed
+// before returning.
+func (b *breakerProvider) ChatStream(ctx context.Context, req core.ChatRequest, ch chan<- core.StreamEvent) (core.ChatResponse, error) {
+	probe, err := b.admit()
+	if err != nil {
+		if ch != nil {
+			close(ch)
+		}
+		return core.ChatResponse{}, err
+	}
+	resp, err := b.inner.ChatStream(ctx, req, ch)
+	b.record(ctx, probe, err)
+	return resp, err
+}
+
+// admit reports whether a call may proceed and whether it is the half-open
+// probe.
+func (b *breakerProvider) admit() (probe bool, err error) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+	if b.fails < b.threshold {
+		return false, nil
+	}
+	// Why: only one caller probes a recovering provider; the rest keep
+	// failing fast until the probe resolves, so recovery isn't met by a
+	// thundering herd.
+	if b.probing || time.Since(b.openedAt) < b.cooldown {
+		return false, ErrCircuitOpen
+	}
+	b.probing = true
+	b.logger.Info("circuit breaker half-open, probing provider", "provider", b.inner.Name())
+	return true, nil
+}
+
+// record updates the circuit with a call's outcome.
+func (b *breakerProvider) record(ctx context.Context, probe bool, err error) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+	if probe {
+		b.probing = false
+	}
+	if err != nil && ctx.Err() != nil {
+		return // the caller gave up; says nothing about the provider
+	}
+	if err == nil || isClientError(err) {
+		if b.fails >= b.threshold {
+			b.logger.Info("circuit breaker closed", "provider", b.inner.Name())
+		}
+		b.fails = 0
+		return
+	}
+	b.fails++
+	if probe || b.fails == b.threshold {
+		b.openedAt = time.Now()
+		b.logger.Warn("circuit breaker open",
+			"provider", b.inner.Name(),
+			"consecutive_failures", b.fails,
+			"cooldown", b.cooldown,
+			"error", err)
+	}
+}
+
+// isClientError reports whether err is an HTTP 4xx other than 408 (request
+// timeout) and 429 (rate limited), i.e. a problem with the request rather
+// than the provider.
+func isClientError(err error) bool {
+	var e *core.ErrHTTP
+	if !errors.As(err, &e) {
+		return false
+	}
+	return e.Status >= 400 && e.Status < 500 && e.Status != 408 && e.Status != 429
+}
+
+// compile-time check
+var _ core.Provider = (*breakerProvider)(nil)
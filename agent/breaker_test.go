@@ -0,0 +1,83 @@
+package agent
+
+import (
+	"context"
+	"errors"
+	"testing"
+	"time"
+
+	"github.com/nevindra/oasis/core"
+)
+
+func TestCircuitBreaker_OpensAndShortCircuits(t *testing.T) {
+	down := &core.ErrHTTP{Status: 503}
+	stub := &stubProvider{results: []stubResult{{err: down}, {err: down}, {err: down}}}
+	p := CircuitBreakerMiddleware(2, time.Hour)(stub)
+	ctx := context.Background()
+
+	for i := 0; i < 2; i++ {
+		if _, err := core.Chat(ctx, p, core.ChatRequest{}); !errors.As(err, new(*core.ErrHTTP)) {
+			t.Fatalf("call %d: err = %v, want the provider's error", i, err)
+		}
+	}
+	_, err := core.Chat(ctx, p, core.ChatRequest{})
+	if !errors.Is(err, ErrCircuitOpen) {
+		t.Fatalf("err = %v, want ErrCircuitOpen", err)
+	}
+	if stub.calls != 2 {
+		t.Errorf("provider called %d times, want 2 (open circuit must not call it)", stub.calls)
+	}
+
+	// Streaming callers still get their channel closed.
+	ch := make(chan core.StreamEvent, 1)
+	if _, err := p.ChatStream(ctx, core.ChatRequest{}, ch); !errors.Is(err, ErrCircuitOpen) {
+		t.Fatalf("stream err = %v, want ErrCircuitOpen", err)
+	}
+	if _, ok := <-ch; ok {
+		t.Error("channel not closed")
+	}
+}
+
+func TestCircuitBreaker_ProbeAfterCooldown(t *testing.T) {
+	down := &core.ErrHTTP{Status: 503}
+	stub := &stubProvider{results: []stubResult{
+		{err: down},
+		{err: down}, // failed probe re-opens
+		{resp: core.ChatResponse{Content: "ok"}},
+		{resp: core.ChatResponse{Content: "ok"}},
+	}}
+	p := CircuitBreakerMiddleware(1, 10*time.Millisecond)(stub)
+	ctx := context.Background()
+
+	_, _ = core.Chat(ctx, p, core.ChatRequest{})
+	time.Sleep(15 * time.Millisecond)
+	if _, err := core.Chat(ctx, p, core.ChatRequest{}); !errors.As(err, new(*core.ErrHTTP)) {
+		t.Fatalf("probe err = %v, want the provider's error", err)
+	}
+	if _, err := core.Chat(ctx, p, core.ChatRequest{}); !errors.Is(err, ErrCircuitOpen) {
+		t.Fatalf("after failed probe err = %v, want ErrCircuitOpen", err)
+	}
+
+	time.Sleep(15 * time.Millisecond)
+	for i := 0; i < 2; i++ {
+		if resp, err := core.Chat(ctx, p, core.ChatRequest{}); err != nil || resp.Content != "ok" {
+			t.Fatalf("call %d after recovery: %q, %v", i, resp.Content, err)
+		}
+	}
+}
+
+func TestCircuitBreaker_IgnoresClientErrors(t *testing.T) {
+	stub := &stubProvider{results: []stubResult{
+		{err: &core.ErrHTTP{Status: 400}},
+		{err: &core.ErrHTTP{Status: 400}},
+		{resp: core.ChatResponse{Content: "ok"}},
+	}}
+	p := CircuitBreakerMiddleware(2, time.Hour)(stub)
+	ctx := context.Background()
+
+	_, _ = core.Chat(ctx, p, core.ChatRequest{})
+	_, _ = core.Chat(ctx, p, core.ChatRequest{})
+	if _, err := core.Chat(ctx, p, core.ChatRequest{}); err != nil {
+		t.Fatalf("bad requests must not open the circuit: %v", err)
+	}
+}
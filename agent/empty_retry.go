@@ -0,0 +1,123 @@
+package agent
+
+import (
+	"context"
+	"strings"
+
+	"github.com/nevindra/oasis/core"
+	"github.com/nevindra/oasis/provider"
+)
+
+// emptyResponseNudge is appended as a user message before retrying a call
+// that came back empty.
+const emptyResponseNudge = "Your previous reply was empty. Please answer the last message."
+
+// EmptyResponseRetryMiddleware returns a provider.Middleware that retries a
+// call which succeeded but produced nothing: no text, no tool calls, and no
+// attachments. Providers occasionally do this on a transient hiccup, and the
+// agent loop would otherwise finish the run with an empty answer. Each retry
+// re-sends the request with a short user nudge appended, up to retries
+// times; if every attempt is empty the last response is returned as is,
+// unless EmptyResponseFallback supplies text to answer with instead. Without
+// a fallback the caller must handle an empty AgentResult.Output itself.
+//
+// A response the provider reports as FinishContentFilter or FinishLength is
+// never retried: the finish reason already explains the empty output, and
+// the agent loop surfaces it on AgentResult.FinishReason. A streaming call
+// is retried only when the empty attempt emitted no events. Each retry adds
+// a warning to ChatResponse.Warnings and its usage to ChatResponse.Usage.
+//
+//	p := provider.Chain(
+//	    agent.EmptyResponseRetryMiddleware(1),
+//	    agent.RetryMiddleware(),
+//	)(base)
+//
+// Panics if retries <= 0.
+func EmptyResponseRetryMiddleware(retries int, opts ...EmptyRetryOption) provider.Middleware {
+	if retries <= 0 {
+		panic("agent.EmptyResponseRetryMiddleware: retries must be > 0")
+	}
+	return func(p core.Provider) core.Provider {
+		e := &emptyRetryProvider{inner: p, retries: retries}
+		for _, o := range opts {
+			o(e)
+		}
+		return e
+	}
+}
+
+// EmptyRetryOption configures EmptyResponseRetryMiddleware.
+type EmptyRetryOption func(*emptyRetryProvider)
+
+// EmptyResponseFallback sets text to answer with when every attempt came
+// back empty, e.g. "I didn't get a response — please try rephrasing." The
+// text becomes the response content (and is streamed as one text delta) and
+// a warning is added. Default: none, the empty response is returned.
+func EmptyResponseFallback(text string) EmptyRetryOption {
+	return func(e *emptyRetryProvider) { e.fallback = text }
+}
+
+// emptyRetryProvider is the Provider returned by EmptyResponseRetryMiddleware.
+type emptyRetryProvider struct {
+	inner    core.Provider
+	retries  int
+	fallback string
+}
+
+// Name delegates to the inner provider.
+func (e *emptyRetryProvider) Name() string { return e.inner.Name() }
+
+// ChatStream implements Provider. ch is always closed before returning.
+func (e *emptyRetryProvider) ChatStream(ctx context.Context, req core.ChatRequest, ch chan<- core.StreamEvent) (core.ChatResponse, error) {
+	var (
+		usage    core.Usage
+		warnings []string
+	)
+	for i := 0; ; i++ {
+		var (
+			resp core.ChatResponse
+			sent bool
+			err  error
+		)
+		if ch == nil {
+			resp, err = e.inner.ChatStream(ctx, req, nil)
+		} else {
+			resp, sent, err = provider.ForwardStream(ctx, e.inner, req, ch)
+		}
+		resp.Usage.InputTokens += usage.InputTokens
+		resp.Usage.OutputTokens += usage.OutputTokens
+		resp.Warnings = append(warnings, resp.Warnings...)
+		if err != nil || sent || i == e.retries || !isEmptyResponse(resp) || ctx.Err() != nil {
+			if err == nil && !sent && e.fallback != "" && isEmptyResponse(resp) && ctx.Err() == nil {
+				resp.Content = e.fallback
+				resp.Warnings = append(resp.Warnings, "empty response from "+e.inner.Name()+", answered with fallback")
+				if ch != nil {
+					select {
+					case ch <- core.StreamEvent{Type: core.EventTextDelta, Content: e.fallback}:
+					case <-ctx.Done():
+					}
+				}
+			}
+			if ch != nil {
+				close(ch)
+			}
+			return resp, err
+		}
+		usage = resp.Usage
+		warnings = append(resp.Warnings, "empty response from "+e.inner.Name()+", retried")
+		req.Messages = append(req.Messages[:len(req.Messages):len(req.Messages)],
+			core.UserMessage(emptyResponseNudge))
+	}
+}
+
+// isEmptyResponse reports whether resp carries nothing for the agent loop to
+// act on and no finish reason that accounts for it.
+func isEmptyResponse(resp core.ChatResponse) bool {
+	if resp.FinishReason == core.FinishContentFilter || resp.FinishReason == core.FinishLength {
+		return false
+	}
+	return strings.TrimSpace(resp.Content) == "" && len(resp.ToolCalls) == 0 && len(resp.Attachments) == 0
+}
+
+// compile-time check
+var _ core.Provider = (*emptyRetryProvider)(nil)
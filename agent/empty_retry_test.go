@@ -0,0 +1,102 @@
+package agent
+
+import (
+	"context"
+	"testing"
+
+	"github.com/nevindra/oasis/core"
+)
+
+// recordingProvider answers from a script and records each request.
+type recordingProvider struct {
+	stubProvider
+	reqs []core.ChatRequest
+}
+
+func (r *recordingProvider) ChatStream(ctx context.Context, req core.ChatRequest, ch chan<- core.StreamEvent) (core.ChatResponse, error) {
+	r.reqs = append(r.reqs, req)
+	return r.stubProvider.ChatStream(ctx, req, ch)
+}
+
+func TestEmptyResponseRetry_RetriesWithNudge(t *testing.T) {
+	rec := &recordingProvider{stubProvider: stubProvider{results: []stubResult{
+		{resp: core.ChatResponse{Usage: core.Usage{InputTokens: 10}}},
+		{resp: core.ChatResponse{Content: "hi", Usage: core.Usage{InputTokens: 12}}, tokens: []string{"hi"}},
+	}}}
+	p := EmptyResponseRetryMiddleware(1)(rec)
+	req := core.ChatRequest{Messages: []core.ChatMessage{core.UserMessage("hello")}}
+
+	ch := make(chan core.StreamEvent, 4)
+	resp, err := p.ChatStream(context.Background(), req, ch)
+	if err != nil {
+		t.Fatal(err)
+	}
+	var streamed string
+	for ev := range ch {
+		streamed += ev.Content
+	}
+	if resp.Content != "hi" || streamed != "hi" {
+		t.Errorf("content = %q, streamed = %q; want hi", resp.Content, streamed)
+	}
+	if resp.Usage.InputTokens != 22 {
+		t.Errorf("InputTokens = %d, want both attempts counted (22)", resp.Usage.InputTokens)
+	}
+	if len(resp.Warnings) != 1 {
+		t.Errorf("Warnings = %v, want one retry warning", resp.Warnings)
+	}
+	if len(rec.reqs) != 2 {
+		t.Fatalf("calls = %d, want 2", len(rec.reqs))
+	}
+	retried := rec.reqs[1].Messages
+	if len(retried) != 2 || retried[1].Content != emptyResponseNudge {
+		t.Errorf("retry messages = %v, want the nudge appended", retried)
+	}
+	if len(req.Messages) != 1 {
+		t.Error("caller's request was mutated")
+	}
+}
+
+func TestEmptyResponseRetry_GivesUpAndSkipsContentFilter(t *testing.T) {
+	stub := &stubProvider{}
+	resp, err := core.Chat(context.Background(), EmptyResponseRetryMiddleware(2)(stub), core.ChatRequest{})
+	if err != nil || resp.Content != "" {
+		t.Fatalf("got %q, %v; want the empty response", resp.Content, err)
+	}
+	if stub.calls != 3 {
+		t.Errorf("calls = %d, want 3 (one plus two retries)", stub.calls)
+	}
+
+	blocked := &stubProvider{results: []stubResult{{resp: core.ChatResponse{FinishReason: core.FinishContentFilter}}}}
+	resp, _ = core.Chat(context.Background(), EmptyResponseRetryMiddleware(2)(blocked), core.ChatRequest{})
+	if blocked.calls != 1 || resp.FinishReason != core.FinishContentFilter {
+		t.Errorf("calls = %d, reason = %q; a filtered response must not be retried", blocked.calls, resp.FinishReason)
+	}
+}
+
+func TestEmptyResponseRetry_Fallback(t *testing.T) {
+	const text = "I didn't get a response — please try rephrasing."
+	stub := &stubProvider{}
+	p := EmptyResponseRetryMiddleware(1, EmptyResponseFallback(text))(stub)
+
+	ch := make(chan core.StreamEvent, 4)
+	resp, err := p.ChatStream(context.Background(), core.ChatRequest{}, ch)
+	if err != nil {
+		t.Fatal(err)
+	}
+	var streamed string
+	for ev := range ch {
+		streamed += ev.Content
+	}
+	if resp.Content != text || streamed != text {
+		t.Errorf("content = %q, streamed = %q; want the fallback", resp.Content, streamed)
+	}
+	if stub.calls != 2 || len(resp.Warnings) != 2 {
+		t.Errorf("calls = %d, warnings = %v; want 2 calls, a retry and a fallback warning", stub.calls, resp.Warnings)
+	}
+
+	answered := &stubProvider{results: []stubResult{{resp: core.ChatResponse{Content: "ok"}}}}
+	resp, _ = core.Chat(context.Background(), EmptyResponseRetryMiddleware(1, EmptyResponseFallback(text))(answered), core.ChatRequest{})
+	if resp.Content != "ok" {
+		t.Errorf("content = %q, the fallback must only replace an empty response", resp.Content)
+	}
+}
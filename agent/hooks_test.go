@@ -4,6 +4,7 @@ import (
 	"context"
 	"encoding/json"
 	"errors"
+	"strings"
 	"sync"
 	"testing"
 
@@ -365,6 +366,76 @@ func TestOnIterationComplete_InjectFeedback(t *testing.T) {
 	}
 }
 
+func TestFinalResponse_ReportsFinishLength(t *testing.T) {
+	provider := &mockProvider{name: "test", responses: []core.ChatResponse{
+		{Content: "The three causes are: first,", FinishReason: core.FinishLength},
+	}}
+	a := New("a", "d", provider)
+	result, err := a.Execute(context.Background(), core.AgentTask{Input: "x"})
+	if err != nil {
+		t.Fatalf("Execute: %v", err)
+	}
+	if result.FinishReason != core.FinishLength {
+		t.Fatalf("FinishReason = %q, want %q", result.FinishReason, core.FinishLength)
+	}
+}
+
+func TestFinalResponse_ReportsContentFilter(t *testing.T) {
+	provider := &mockProvider{name: "test", responses: []core.ChatResponse{
+		{FinishReason: core.FinishContentFilter},
+	}}
+	a := New("a", "d", provider)
+	result, err := a.Execute(context.Background(), core.AgentTask{Input: "x"})
+	if err != nil {
+		t.Fatalf("Execute: %v", err)
+	}
+	if result.FinishReason != core.FinishContentFilter {
+		t.Fatalf("FinishReason = %q, want %q", result.FinishReason, core.FinishContentFilter)
+	}
+}
+
+func TestOnIterationComplete_ContinuesTruncatedResponse(t *testing.T) {
+	// A hook that detects max_tokens truncation and asks the model to resume;
+	// the partial answer stays in context so the continuation picks up from it.
+	var parts []string
+	hook := func(ctx context.Context, iter int, snap *IterationSnapshot) (IterationDecision, error) {
+		parts = append(parts, snap.Response.Content)
+		if snap.Response.FinishReason == core.FinishLength {
+			return InjectFeedback("Continue exactly where you left off."), nil
+		}
+		return Continue(), nil
+	}
+	var lastReq core.ChatRequest
+	provider := &mockProvider{
+		name: "test",
+		responses: []core.ChatResponse{
+			{Content: "The three causes are: first,", FinishReason: core.FinishLength},
+			{Content: " inflation; second, rates; third, supply.", FinishReason: core.FinishStop},
+		},
+		onChat: func(req *core.ChatRequest) { lastReq = *req },
+	}
+	a := New("a", "d", provider, WithHooks(Hooks{OnIterationComplete: hook}))
+	result, err := a.Execute(context.Background(), core.AgentTask{Input: "x"})
+	if err != nil {
+		t.Fatalf("Execute: %v", err)
+	}
+	if result.FinishReason != core.FinishStop {
+		t.Errorf("FinishReason = %q, want %q after continuation", result.FinishReason, core.FinishStop)
+	}
+	if got := strings.Join(parts, ""); got != "The three causes are: first, inflation; second, rates; third, supply." {
+		t.Errorf("joined output = %q", got)
+	}
+	var sawPartial bool
+	for _, m := range lastReq.Messages {
+		if m.Role == core.RoleAssistant && m.Content == "The three causes are: first," {
+			sawPartial = true
+		}
+	}
+	if !sawPartial {
+		t.Errorf("continuation request must carry the partial answer, got %+v", lastReq.Messages)
+	}
+}
+
 func TestOnIterationComplete_HookErrorPropagates(t *testing.T) {
 	hookErr := errors.New("hook failed")
 	hook := func(ctx context.Context, iter int, snap *IterationSnapshot) (IterationDecision, error) {
@@ -406,16 +406,24 @@ func runIteration(ctx context.Context, cfg *LoopConfig, task AgentTask, ch chan<
 			// Continue: fall through to natural iterDone.
 		}
 
-		endIteration(ep, core.FinishStop)
+		// Why: a final answer cut off by max_tokens or blocked by a safety
+		// filter must not be reported as a clean stop — callers key
+		// "continue" affordances off FinishLength and tell a refusal apart
+		// from an empty generation by FinishContentFilter.
+		reason := core.FinishStop
+		if resp.FinishReason == core.FinishLength || resp.FinishReason == core.FinishContentFilter {
+			reason = resp.FinishReason
+		}
+		endIteration(ep, reason)
 		cfg.Mem.PersistTurn(iterCtx, cfg.Name, task, task.Input, content, state.steps)
 		result := AgentResult{
 			Output:      content,
 			Thinking:    state.lastThinking,
 			Attachments: mergeAttachments(state.accumulatedAttachments, resp.Attachments),
 		}
-		state.patchTerminal(&result, core.FinishStop)
+		state.patchTerminal(&result, reason)
 		emitObjectFinish(ctx, ch, cfg.ResponseSchema, content, &result)
-		finalizeRun(ctx, ch, state, cfg.Name, core.FinishStop, result)
+		finalizeRun(ctx, ch, state, cfg.Name, reason, result)
 		return iterationResult{
 			outcome: iterDone,
 			final:   result,
@@ -575,6 +583,7 @@ func runIteration(ctx context.Context, cfg *LoopConfig, task AgentTask, ch chan<
 				Content:  displayContent,
 				Usage:    results[j].usage,
 				Duration: results[j].duration,
+				IsError:  results[j].isError,
 			}:
 			case <-ctx.Done():
 			}
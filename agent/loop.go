@@ -2,8 +2,10 @@ package agent
 
 import (
 	"context"
+	"log/slog"
 	"strings"
 	"sync"
+	"time"
 	"unicode/utf8"
 
 	"github.com/nevindra/oasis/core"
@@ -112,16 +114,54 @@ func runLoop(ctx context.Context, cfg *LoopConfig, task AgentTask, ch chan<- cor
 	state := acquireLoopState(messages, messageRuneCount, attachByteBudget, hasAgentTools, cfg.CompressThreshold, ch)
 	defer releaseLoopState(state)
 
+	start := time.Now()
+	result, err := iterate(ctx, cfg, task, ch, state)
+	logRunSummary(ctx, cfg.Logger, cfg.Name, result, err, time.Since(start))
+	return result, err
+}
+
+// iterate runs iterations until one ends the run, falling back to forced
+// synthesis once cfg.MaxIter is reached.
+func iterate(ctx context.Context, cfg *LoopConfig, task AgentTask, ch chan<- core.StreamEvent, state *loopState) (AgentResult, error) {
 	for i := 0; i < cfg.MaxIter; i++ {
 		result := runIteration(ctx, cfg, task, ch, state, i)
 		if result.outcome == iterDone {
 			return result.final, result.err
 		}
 	}
-
 	return forceSynthesis(ctx, cfg, task, ch, state)
 }
 
+// logRunSummary emits one Info line per run with its token cost, call
+// counts, and wall time, so operators can see how expensive and slow each
+// interaction was without stitching together per-iteration logs.
+func logRunSummary(ctx context.Context, logger *slog.Logger, name string, result AgentResult, err error, elapsed time.Duration) {
+	var toolCalls, agentCalls int
+	for _, s := range result.Steps {
+		switch s.Type {
+		case core.StepTypeTool:
+			toolCalls++
+		case core.StepTypeAgent:
+			agentCalls++
+		}
+	}
+	attrs := []any{
+		"agent", name,
+		"finish_reason", result.FinishReason,
+		"llm_calls", len(result.Iterations),
+		"tool_calls", toolCalls,
+		"agent_calls", agentCalls,
+		"embed_calls", core.RunEmbedCalls(ctx),
+		"input_tokens", result.Usage.InputTokens,
+		"output_tokens", result.Usage.OutputTokens,
+		"duration", elapsed,
+	}
+	if err != nil {
+		attrs = append(attrs, "error", err)
+	}
+	logger.Info("agent run summary", attrs...)
+}
+
 // finalizeRun emits EventRunFinish and closes the streaming channel.
 func finalizeRun(ctx context.Context, ch chan<- core.StreamEvent, state *loopState, name string, reason core.FinishReason, result AgentResult) {
 	if ch != nil {
@@ -153,7 +193,8 @@ func finalizeRun(ctx context.Context, ch chan<- core.StreamEvent, state *loopSta
 func forceSynthesis(ctx context.Context, cfg *LoopConfig, task AgentTask, ch chan<- core.StreamEvent, state *loopState) (AgentResult, error) {
 	cfg.Logger.Warn("max iterations reached, forcing synthesis", "agent", cfg.Name, "iteration", cfg.MaxIter)
 	state.messages = append(state.messages, core.UserMessage(
-		"You have used all available tool calls. Summarize what you found and respond to the user."))
+		"You have used all available tool calls. Tell the user what you accomplished, "+
+			"what remains unfinished, and what they could do next."))
 
 	// Synthesis span so the forced-response LLM call is visible in traces.
 	synthCtx := ctx
@@ -1,9 +1,11 @@
 package agent
 
 import (
+	"bytes"
 	"context"
 	"encoding/json"
 	"fmt"
+	"log/slog"
 	"reflect"
 	"strings"
 	"sync/atomic"
@@ -808,3 +810,84 @@ func TestSplitContentRunes_FourByteRuneNarrowMax(t *testing.T) {
 		t.Errorf("reassembled chunks do not equal original: %v", chunks)
 	}
 }
+
+func TestRunLoop_FlagsToolErrors(t *testing.T) {
+	provider := &mockProvider{
+		name: "test",
+		responses: []core.ChatResponse{
+			{ToolCalls: []core.ToolCall{
+				{ID: "1", Name: "greet", Args: json.RawMessage(`{}`)},
+				{ID: "2", Name: "fail", Args: json.RawMessage(`{}`)},
+			}},
+			{Content: "done"},
+		},
+	}
+	agent := New("flags", "", provider, WithTools(mockTool{}, errTool{}))
+
+	ch := make(chan core.StreamEvent, 64)
+	result, err := agent.Execute(context.Background(), AgentTask{Input: "go"}, core.WithStream(ch))
+	if err != nil {
+		t.Fatal(err)
+	}
+	failed := map[string]bool{}
+	for ev := range ch {
+		if ev.Type == core.EventToolCallResult {
+			failed[ev.Name] = ev.IsError
+		}
+	}
+	if failed["greet"] || !failed["fail"] {
+		t.Errorf("tool-call-result IsError = %v, want only fail flagged", failed)
+	}
+
+	if len(result.Steps) != 2 || result.Steps[0].IsError || !result.Steps[1].IsError {
+		t.Fatalf("step IsError flags wrong: %+v", result.Steps)
+	}
+	results := result.ToolResults()
+	if results[0].Error != "" || results[1].Error != "tool broken" || results[1].Content != "" {
+		t.Errorf("ToolResults = %+v, want fail's message in Error", results)
+	}
+}
+
+func TestRunLoop_LogsRunSummary(t *testing.T) {
+	var buf bytes.Buffer
+	logger := slog.New(slog.NewJSONHandler(&buf, nil))
+	provider := &mockProvider{
+		name: "test",
+		responses: []core.ChatResponse{
+			{
+				ToolCalls: []core.ToolCall{
+					{ID: "1", Name: "greet", Args: json.RawMessage(`{}`)},
+					{ID: "2", Name: "calc", Args: json.RawMessage(`{}`)},
+				},
+				Usage: core.Usage{InputTokens: 100, OutputTokens: 20},
+			},
+			{Content: "done", Usage: core.Usage{InputTokens: 150, OutputTokens: 30}},
+		},
+	}
+	agent := New("summarized", "", provider,
+		WithTools(mockTool{}, mockToolCalc{}), WithLogger(logger))
+
+	if _, err := agent.Execute(context.Background(), AgentTask{Input: "go"}); err != nil {
+		t.Fatal(err)
+	}
+
+	var summary map[string]any
+	for _, line := range strings.Split(strings.TrimSpace(buf.String()), "\n") {
+		var rec map[string]any
+		if json.Unmarshal([]byte(line), &rec) == nil && rec["msg"] == "agent run summary" {
+			summary = rec
+		}
+	}
+	if summary == nil {
+		t.Fatalf("no run summary logged:\n%s", buf.String())
+	}
+	want := map[string]float64{"llm_calls": 2, "tool_calls": 2, "input_tokens": 250, "output_tokens": 50}
+	for k, v := range want {
+		if summary[k] != v {
+			t.Errorf("%s = %v, want %v", k, summary[k], v)
+		}
+	}
+	if summary["finish_reason"] != string(core.FinishStop) {
+		t.Errorf("finish_reason = %v, want %q", summary["finish_reason"], core.FinishStop)
+	}
+}
@@ -0,0 +1,74 @@
+package agent
+
+import (
+	"context"
+	"os"
+	"strings"
+	"sync"
+	"time"
+)
+
+// FilePrompt returns a PromptFunc that reads the system prompt from the file
+// at path, so operators can change an agent's instructions without a
+// rebuild. Use it with WithDynamicPrompt:
+//
+//	agent.New("assistant", "", llm, agent.WithDynamicPrompt(
+//	    agent.FilePrompt("prompts/assistant.md", defaultPrompt, promptVars)))
+//
+// The file is re-read when its modification time or size changes, so edits
+// apply to the next run without a restart. When the file is missing or
+// unreadable, fallback is used instead.
+//
+// Placeholders of the form {name} are replaced with the values vars returns
+// for each request (e.g. {now}, {tz}); placeholders without a value are left
+// as is. vars may be nil. Safe for concurrent use.
+func FilePrompt(path, fallback string, vars func(ctx context.Context, task AgentTask) map[string]string) PromptFunc {
+	fp := &filePrompt{path: path, fallback: fallback}
+	return func(ctx context.Context, task AgentTask) string {
+		prompt := fp.load()
+		if vars == nil {
+			return prompt
+		}
+		values := vars(ctx, task)
+		if len(values) == 0 {
+			return prompt
+		}
+		pairs := make([]string, 0, 2*len(values))
+		for name, value := range values {
+			pairs = append(pairs, "{"+name+"}", value)
+		}
+		return strings.NewReplacer(pairs...).Replace(prompt)
+	}
+}
+
+// filePrompt caches a prompt file's contents, keyed by its mod time and size.
+type filePrompt struct {
+	path     string
+	fallback string
+
+	mu      sync.Mutex
+	text    string
+	modTime time.Time
+	size    int64
+	loaded  bool
+}
+
+// load returns the file's current contents, re-reading it only when it has
+// changed since the last read, or the fallback when it cannot be read.
+func (fp *filePrompt) load() string {
+	info, err := os.Stat(fp.path)
+	if err != nil {
+		return fp.fallback
+	}
+	fp.mu.Lock()
+	defer fp.mu.Unlock()
+	if fp.loaded && info.ModTime().Equal(fp.modTime) && info.Size() == fp.size {
+		return fp.text
+	}
+	data, err := os.ReadFile(fp.path)
+	if err != nil {
+		return fp.fallback
+	}
+	fp.text, fp.modTime, fp.size, fp.loaded = string(data), info.ModTime(), info.Size(), true
+	return fp.text
+}
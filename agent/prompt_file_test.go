@@ -0,0 +1,44 @@
+package agent
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+)
+
+func TestFilePrompt(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "prompt.md")
+	if err := os.WriteFile(path, []byte("You are {name}. Today is {now}. Keep {unknown}."), 0o644); err != nil {
+		t.Fatal(err)
+	}
+	vars := func(_ context.Context, task AgentTask) map[string]string {
+		return map[string]string{"name": "Oasis", "now": "2026-01-02", "input": task.Input}
+	}
+	prompt := FilePrompt(path, "fallback", vars)
+	ctx := context.Background()
+
+	if got, want := prompt(ctx, AgentTask{}), "You are Oasis. Today is 2026-01-02. Keep {unknown}."; got != want {
+		t.Errorf("prompt = %q, want %q", got, want)
+	}
+
+	// Edits are picked up without rebuilding the PromptFunc.
+	if err := os.WriteFile(path, []byte("Reply to: {input}"), 0o644); err != nil {
+		t.Fatal(err)
+	}
+	later := time.Now().Add(time.Minute)
+	if err := os.Chtimes(path, later, later); err != nil {
+		t.Fatal(err)
+	}
+	if got := prompt(ctx, AgentTask{Input: "hi"}); got != "Reply to: hi" {
+		t.Errorf("after edit prompt = %q, want %q", got, "Reply to: hi")
+	}
+
+	if err := os.Remove(path); err != nil {
+		t.Fatal(err)
+	}
+	if got := prompt(ctx, AgentTask{}); got != "fallback" {
+		t.Errorf("missing file prompt = %q, want fallback", got)
+	}
+}
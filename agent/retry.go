@@ -5,6 +5,8 @@ import (
 	"errors"
 	"log/slog"
 	"math/rand"
+	"slices"
+	"sync/atomic"
 	"time"
 
 	"github.com/nevindra/oasis/core"
@@ -18,9 +20,15 @@ type retryProvider struct {
 	maxAttempts int
 	baseDelay   time.Duration
 	timeout     time.Duration // overall timeout across all attempts; 0 = no limit
+	attempt     time.Duration // per-attempt timeout; 0 = no limit
 	logger      *slog.Logger  // nil = nopLogger
+	statuses    []int         // transient HTTP statuses; nil = defaultTransientStatuses
 }
 
+// defaultTransientStatuses are the HTTP statuses retried when RetryOnStatus
+// is not set.
+var defaultTransientStatuses = []int{429, 503}
+
 // RetryOption configures a retryProvider.
 type RetryOption func(*retryProvider)
 
@@ -42,6 +50,25 @@ func RetryTimeout(d time.Duration) RetryOption {
 	return func(r *retryProvider) { r.timeout = d }
 }
 
+// RetryAttemptTimeout bounds each attempt (default: no limit). A
+// non-streaming call must complete, and a streaming call must deliver its
+// first event, within d; once a stream is flowing it is bounded only by
+// RetryTimeout and the caller's context. An attempt that exceeds d is
+// cancelled and retried like a transient error, so a provider that hangs
+// without responding no longer stalls the run. When every attempt times out
+// the returned error matches context.DeadlineExceeded.
+func RetryAttemptTimeout(d time.Duration) RetryOption {
+	return func(r *retryProvider) { r.attempt = d }
+}
+
+// RetryOnStatus replaces the set of HTTP statuses treated as transient
+// (default 429 and 503). Use it to also retry gateway errors, e.g.
+// RetryOnStatus(429, 500, 502, 503, 504) for an embedding endpoint behind a
+// flaky proxy. Errors that are not *core.ErrHTTP are never retried.
+func RetryOnStatus(codes ...int) RetryOption {
+	return func(r *retryProvider) { r.statuses = codes }
+}
+
 // RetryLogger sets the structured logger for retry events. When set, retries
 // log at WARN level and final failures after exhausting attempts log at ERROR.
 // If not set, a no-op logger is used (no output).
@@ -64,6 +91,9 @@ func newRetryProvider(p core.Provider, opts ...RetryOption) *retryProvider {
 	if r.logger == nil {
 		r.logger = nopLogger
 	}
+	if r.statuses == nil {
+		r.statuses = defaultTransientStatuses
+	}
 	return r
 }
 
@@ -82,8 +112,11 @@ func (r *retryProvider) ChatStream(ctx context.Context, req core.ChatRequest, ch
 	if ch == nil {
 		var lastErr error
 		for i := 0; i < r.maxAttempts; i++ {
-			resp, err := r.inner.ChatStream(ctx, req, nil)
-			if err == nil || !isTransient(err) {
+			attemptCtx, cancelAttempt := attemptContext(ctx, r.attempt)
+			resp, err := r.inner.ChatStream(attemptCtx, req, nil)
+			err = attemptErr(ctx, attemptCtx, err, r.attempt)
+			cancelAttempt()
+			if err == nil || !isTransient(err, r.statuses) {
 				return resp, err
 			}
 			lastErr = err
@@ -117,15 +150,29 @@ func (r *retryProvider) ChatStream(ctx context.Context, req core.ChatRequest, ch
 			resp      core.ChatResponse
 			streamErr error
 		)
+		attemptCtx, cancelAttempt := context.WithCancel(ctx)
+		var (
+			firstEvent *time.Timer
+			timedOut   atomic.Bool
+		)
+		if r.attempt > 0 {
+			firstEvent = time.AfterFunc(r.attempt, func() {
+				timedOut.Store(true)
+				cancelAttempt()
+			})
+		}
 		done := make(chan struct{})
 		go func() {
 			defer close(done)
-			resp, streamErr = r.inner.ChatStream(ctx, req, mid)
+			resp, streamErr = r.inner.ChatStream(attemptCtx, req, mid)
 		}()
 
 		var tokensSent bool
 		ctxDone := false
 		for ev := range mid {
+			if firstEvent != nil {
+				firstEvent.Stop()
+			}
 			if ctxDone {
 				continue
 			}
@@ -139,6 +186,13 @@ func (r *retryProvider) ChatStream(ctx context.Context, req core.ChatRequest, ch
 			}
 		}
 		<-done
+		if firstEvent != nil {
+			firstEvent.Stop()
+		}
+		cancelAttempt()
+		if timedOut.Load() && !tokensSent && ctx.Err() == nil {
+			streamErr = &attemptTimeoutError{after: r.attempt}
+		}
 
 		if ctxDone {
 			if ch != nil {
@@ -146,7 +200,7 @@ func (r *retryProvider) ChatStream(ctx context.Context, req core.ChatRequest, ch
 			}
 			return core.ChatResponse{}, ctx.Err()
 		}
-		if streamErr == nil || !isTransient(streamErr) || tokensSent {
+		if streamErr == nil || !isTransient(streamErr, r.statuses) || tokensSent {
 			if ch != nil {
 				close(ch)
 			}
@@ -197,10 +251,45 @@ func (r *retryProvider) withTimeout(ctx context.Context) (context.Context, conte
 	return context.WithDeadline(ctx, deadline)
 }
 
-// isTransient reports whether err is a retryable HTTP error (429 or 503).
-func isTransient(err error) bool {
+// isTransient reports whether err is an HTTP error whose status is in
+// statuses, or an attempt cut off by RetryAttemptTimeout.
+func isTransient(err error, statuses []int) bool {
+	var te *attemptTimeoutError
+	if errors.As(err, &te) {
+		return true
+	}
 	var e *core.ErrHTTP
-	return errors.As(err, &e) && (e.Status == 429 || e.Status == 503)
+	return errors.As(err, &e) && slices.Contains(statuses, e.Status)
+}
+
+// attemptTimeoutError reports an attempt cancelled by RetryAttemptTimeout.
+// It unwraps to context.DeadlineExceeded.
+type attemptTimeoutError struct{ after time.Duration }
+
+func (e *attemptTimeoutError) Error() string {
+	return "provider attempt timed out after " + e.after.String()
+}
+
+func (e *attemptTimeoutError) Unwrap() error { return context.DeadlineExceeded }
+
+// attemptContext derives the context for a single attempt, bounded by d when
+// d is positive. The caller must call the returned CancelFunc.
+func attemptContext(ctx context.Context, d time.Duration) (context.Context, context.CancelFunc) {
+	if d <= 0 {
+		return ctx, func() {}
+	}
+	return context.WithTimeout(ctx, d)
+}
+
+// attemptErr replaces err with an *attemptTimeoutError when the attempt's own
+// deadline, not the caller's context, ended it. Providers surface the
+// cancellation in different shapes (wrapped ErrLLM, raw context error), so
+// the contexts are inspected rather than err.
+func attemptErr(ctx, attemptCtx context.Context, err error, d time.Duration) error {
+	if err != nil && ctx.Err() == nil && errors.Is(attemptCtx.Err(), context.DeadlineExceeded) {
+		return &attemptTimeoutError{after: d}
+	}
+	return err
 }
 
 // statusOf extracts the HTTP status code from an ErrHTTP, or 0.
@@ -233,12 +322,12 @@ func retryDelay(base time.Duration, i int, err error) time.Duration {
 }
 
 // retryCall calls fn up to maxAttempts times, sleeping between transient failures.
-func retryCall[T any](ctx context.Context, maxAttempts int, base time.Duration, name string, logger *slog.Logger, fn func() (T, error)) (T, error) {
+func retryCall[T any](ctx context.Context, maxAttempts int, base time.Duration, statuses []int, name string, logger *slog.Logger, fn func() (T, error)) (T, error) {
 	var zero T
 	var last error
 	for i := 0; i < maxAttempts; i++ {
 		result, err := fn()
-		if err == nil || !isTransient(err) {
+		if err == nil || !isTransient(err, statuses) {
 			return result, err
 		}
 		last = err
@@ -280,10 +369,15 @@ type retryEmbeddingProvider struct {
 	maxAttempts int
 	baseDelay   time.Duration
 	timeout     time.Duration
+	attempt     time.Duration
 	logger      *slog.Logger
+	statuses    []int
 }
 
-// WithEmbeddingRetry wraps p with automatic retry on transient HTTP errors (429, 503).
+// WithEmbeddingRetry wraps p with automatic retry on transient HTTP errors
+// (429, 503; widen with RetryOnStatus). Each Embed call is retried as a unit,
+// so with the ingestor's batching (ingest.WithBatchSize) only the failed
+// batch is re-sent.
 // Accepts the same RetryOption functions as RetryMiddleware. Compose with any EmbeddingProvider:
 //
 //	emb = oasis.WithEmbeddingRetry(gemini.NewEmbedding(apiKey, model))
@@ -295,7 +389,9 @@ func WithEmbeddingRetry(p core.EmbeddingProvider, opts ...RetryOption) core.Embe
 		maxAttempts: cfg.maxAttempts,
 		baseDelay:   cfg.baseDelay,
 		timeout:     cfg.timeout,
+		attempt:     cfg.attempt,
 		logger:      cfg.logger,
+		statuses:    cfg.statuses,
 	}
 }
 
@@ -311,8 +407,11 @@ func (r *retryEmbeddingProvider) Embed(ctx context.Context, texts []string) ([][
 			defer cancel()
 		}
 	}
-	return retryCall(ctx, r.maxAttempts, r.baseDelay, r.inner.Name(), r.logger, func() ([][]float32, error) {
-		return r.inner.Embed(ctx, texts)
+	return retryCall(ctx, r.maxAttempts, r.baseDelay, r.statuses, r.inner.Name(), r.logger, func() ([][]float32, error) {
+		attemptCtx, cancel := attemptContext(ctx, r.attempt)
+		defer cancel()
+		vecs, err := r.inner.Embed(attemptCtx, texts)
+		return vecs, attemptErr(ctx, attemptCtx, err, r.attempt)
 	})
 }
 
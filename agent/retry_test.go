@@ -2,6 +2,7 @@ package agent
 
 import (
 	"context"
+	"errors"
 	"testing"
 	"time"
 
@@ -286,6 +287,74 @@ func TestWithRetry_Chat_TimeoutAllowsSuccess(t *testing.T) {
 	}
 }
 
+// hangingProvider blocks until its context ends for the first hangs calls,
+// then streams "ok".
+type hangingProvider struct {
+	hangs int
+	calls int
+}
+
+func (h *hangingProvider) Name() string { return "hanging" }
+
+func (h *hangingProvider) ChatStream(ctx context.Context, _ core.ChatRequest, ch chan<- core.StreamEvent) (core.ChatResponse, error) {
+	if ch != nil {
+		defer close(ch)
+	}
+	h.calls++
+	if h.calls <= h.hangs {
+		<-ctx.Done()
+		return core.ChatResponse{}, &core.ErrLLM{Provider: "hanging", Message: "request failed: " + ctx.Err().Error()}
+	}
+	if ch != nil {
+		ch <- core.StreamEvent{Type: core.EventTextDelta, Content: "ok"}
+	}
+	return core.ChatResponse{Content: "ok"}, nil
+}
+
+func TestWithRetry_Chat_AttemptTimeoutRetriesHang(t *testing.T) {
+	stub := &hangingProvider{hangs: 1}
+	p := RetryMiddleware(RetryBaseDelay(0), RetryAttemptTimeout(20*time.Millisecond))(stub)
+
+	resp, err := core.Chat(context.Background(), p, core.ChatRequest{})
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if resp.Content != "ok" || stub.calls != 2 {
+		t.Errorf("got %q after %d calls, want %q after 2", resp.Content, stub.calls, "ok")
+	}
+}
+
+func TestWithRetry_Chat_AttemptTimeoutExhausted(t *testing.T) {
+	stub := &hangingProvider{hangs: 5}
+	p := RetryMiddleware(RetryBaseDelay(0), RetryMaxAttempts(2), RetryAttemptTimeout(10*time.Millisecond))(stub)
+
+	_, err := core.Chat(context.Background(), p, core.ChatRequest{})
+	if !errors.Is(err, context.DeadlineExceeded) {
+		t.Fatalf("err = %v, want context.DeadlineExceeded", err)
+	}
+	if stub.calls != 2 {
+		t.Errorf("got %d calls, want 2", stub.calls)
+	}
+}
+
+func TestWithRetry_ChatStream_AttemptTimeoutBeforeFirstEvent(t *testing.T) {
+	stub := &hangingProvider{hangs: 1}
+	p := RetryMiddleware(RetryBaseDelay(0), RetryAttemptTimeout(20*time.Millisecond))(stub)
+
+	ch := make(chan core.StreamEvent, 8)
+	resp, err := p.ChatStream(context.Background(), core.ChatRequest{}, ch)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	var got string
+	for ev := range ch {
+		got += ev.Content
+	}
+	if resp.Content != "ok" || got != "ok" || stub.calls != 2 {
+		t.Errorf("got resp %q, tokens %q after %d calls; want ok, ok after 2", resp.Content, got, stub.calls)
+	}
+}
+
 // --- EmbeddingRetry tests ---
 
 type stubRetryEmbed struct {
@@ -382,6 +451,32 @@ func TestWithEmbeddingRetry_DoesNotRetryNonTransient(t *testing.T) {
 	}
 }
 
+func TestWithEmbeddingRetry_RetryOnStatus(t *testing.T) {
+	vecs := [][]float32{{0.1, 0.2, 0.3}}
+	stub := &stubRetryEmbed{results: []stubRetryEmbedResult{
+		{err: &core.ErrHTTP{Status: 502, Body: "bad gateway"}},
+		{embeddings: vecs},
+	}}
+	p := WithEmbeddingRetry(stub, RetryBaseDelay(0), RetryOnStatus(429, 502, 503))
+
+	got, err := p.Embed(context.Background(), []string{"hello"})
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if len(got) != 1 || stub.calls != 2 {
+		t.Errorf("got %d embeddings after %d calls, want 1 after 2", len(got), stub.calls)
+	}
+
+	// 502 is not transient by default.
+	stub = &stubRetryEmbed{results: []stubRetryEmbedResult{
+		{err: &core.ErrHTTP{Status: 502, Body: "bad gateway"}},
+	}}
+	p = WithEmbeddingRetry(stub, RetryBaseDelay(0))
+	if _, err := p.Embed(context.Background(), []string{"hello"}); err == nil || stub.calls != 1 {
+		t.Errorf("default statuses: err=%v calls=%d, want error after 1 call", err, stub.calls)
+	}
+}
+
 func TestWithEmbeddingRetry_ExhaustsMaxAttempts(t *testing.T) {
 	transient := stubRetryEmbedResult{err: &core.ErrHTTP{Status: 429, Body: "rate limited"}}
 	stub := &stubRetryEmbed{results: []stubRetryEmbedResult{transient, transient, transient, transient}}
@@ -8,6 +8,7 @@ import (
 	"fmt"
 	"log/slog"
 	"net/http"
+	"strings"
 	"sync"
 
 	"github.com/nevindra/oasis/core"
@@ -898,10 +899,15 @@ func (s *Stream) OnToolCall(fn func(core.ToolCall)) {
 
 // OnToolResult registers a callback invoked when a tool returns
 // (EventToolCallResult). fn receives a synthesized ToolResult with the raw
-// Content. To inspect Usage or Duration, use OnEvent and read the
+// Content, or with Error set (without the "error: " prefix) when the tool
+// failed. To inspect Usage or Duration, use OnEvent and read the
 // StreamEvent directly.
 func (s *Stream) OnToolResult(fn func(core.ToolResult)) {
 	s.subscribe(core.EventToolCallResult, func(ev core.StreamEvent) {
+		if ev.IsError {
+			fn(core.ToolResult{Error: strings.TrimPrefix(ev.Content, "error: ")})
+			return
+		}
 		fn(core.ToolResult{Content: ev.Content})
 	})
 }
@@ -200,19 +200,29 @@ func TestStream_OnToolResult(t *testing.T) {
 		events: []core.StreamEvent{
 			{Type: core.EventToolCallResult, Content: "result1"},
 			{Type: core.EventToolCallResult, Content: "result2"},
+			{Type: core.EventToolCallResult, Content: "error: boom", IsError: true},
 		},
 		final: AgentResult{},
 		delay: 10 * time.Millisecond,
 	}
 	s := Subscribe(context.Background(), ag, AgentTask{})
 
-	var got []string
-	s.OnToolResult(func(tr core.ToolResult) { got = append(got, string(tr.Content)) })
+	var got, errs []string
+	s.OnToolResult(func(tr core.ToolResult) {
+		if tr.Error != "" {
+			errs = append(errs, tr.Error)
+			return
+		}
+		got = append(got, string(tr.Content))
+	})
 
 	_, _ = s.Result()
 	if !equalStrings(got, []string{"result1", "result2"}) {
 		t.Errorf("OnToolResult got %v, want [result1 result2]", got)
 	}
+	if !equalStrings(errs, []string{"boom"}) {
+		t.Errorf("OnToolResult errors = %v, want [boom]", errs)
+	}
 }
 
 func TestStream_OnEvent(t *testing.T) {
@@ -4,6 +4,7 @@ import (
 	"encoding/json"
 	"fmt"
 	"log/slog"
+	"unicode/utf8"
 
 	"github.com/nevindra/oasis/core"
 )
@@ -12,6 +13,64 @@ import (
 // when its transform panics. Fail-closed: never leak the raw payload on error.
 const redactionFailed = "[oasis: redaction failed]"
 
+// TruncateToolResult returns a SinkTransform.Result function that caps a tool
+// result's Content at maxRunes runes, marker included, keeping the first
+// three quarters and the last quarter of what fits around a marker noting
+// how much was cut in between. Use it
+// on the Model sink so an oversized search dump or file read cannot crowd out
+// the context window:
+//
+//	agent.WithToolConfig(agent.ToolConfig{
+//	    TransformMatchers: []agent.TransformMatcher{{
+//	        Match: func(string) bool { return true },
+//	        Transform: core.ToolTransform{
+//	            Model: &core.SinkTransform{Result: agent.TruncateToolResult(20_000)},
+//	        },
+//	    }},
+//	})
+//
+// Unlike Limits.MaxToolResultLen, which splits a long result across several
+// messages, this drops the middle. Panics if maxRunes <= 0.
+func TruncateToolResult(maxRunes int) func(name string, r core.ToolResult) core.ToolResult {
+	if maxRunes <= 0 {
+		panic("agent.TruncateToolResult: maxRunes must be > 0")
+	}
+	return func(_ string, r core.ToolResult) core.ToolResult {
+		// Why: len counts bytes, and a string never has more runes than
+		// bytes, so this skips the []rune copy for every result that fits.
+		if len(r.Content) <= maxRunes {
+			return r
+		}
+		runes := []rune(r.Content)
+		if len(runes) <= maxRunes {
+			return r
+		}
+		// Why: the marker counts against the budget, and its length depends
+		// on the number it reports, so shrink keep until the two agree.
+		keep := maxRunes
+		var marker string
+		for {
+			marker = fmt.Sprintf("\n\n[... %d characters truncated ...]\n\n", len(runes)-keep)
+			k := maxRunes - utf8.RuneCountInString(marker)
+			if k == keep || k <= 0 {
+				keep = k
+				break
+			}
+			keep = k
+		}
+		if keep <= 0 {
+			r.Content = string(runes[:maxRunes])
+			return r
+		}
+		// Why: the head usually carries the answer, but the tail is where
+		// shell output and logs put their error or summary line.
+		tail := keep / 4
+		head := keep - tail
+		r.Content = string(runes[:head]) + marker + string(runes[len(runes)-tail:])
+		return r
+	}
+}
+
 // applyResultTransform runs st.Result with panic recovery.
 //   - st nil or st.Result nil: returns r unchanged (passthrough).
 //   - transform panics + failClosed (Display/Transcript): returns a placeholder
@@ -2,7 +2,9 @@ package agent
 
 import (
 	"encoding/json"
+	"strings"
 	"testing"
+	"unicode/utf8"
 
 	"github.com/nevindra/oasis/core"
 )
@@ -62,3 +64,17 @@ func TestApplyArgsTransform_PanicFailClosed(t *testing.T) {
 		t.Errorf("args fail-closed = %s, want quoted placeholder", got)
 	}
 }
+
+func TestTruncateToolResult_CountsRunesAndMarker(t *testing.T) {
+	// 3000 bytes but 1000 runes: within budget, left alone.
+	in := core.ToolResult{Content: strings.Repeat("é", 500) + strings.Repeat("日", 500)}
+	if got := TruncateToolResult(1000)("t", in); got.Content != in.Content {
+		t.Error("a result within maxRunes runes was truncated")
+	}
+	for _, limit := range []int{5, 60, 500} {
+		got := TruncateToolResult(limit)("t", core.ToolResult{Content: strings.Repeat("日", 2000)})
+		if n := utf8.RuneCountInString(got.Content); n != limit {
+			t.Errorf("maxRunes %d: got %d runes", limit, n)
+		}
+	}
+}
@@ -3,6 +3,7 @@ package agent
 import (
 	"context"
 	"encoding/json"
+	"strings"
 	"testing"
 
 	"github.com/nevindra/oasis/core"
@@ -138,3 +139,58 @@ func TestToolTransform_DisplayFailClosedOnPanic(t *testing.T) {
 		}
 	}
 }
+
+// bigTool returns a result far larger than the truncation cap.
+type bigTool struct{}
+
+func (bigTool) Name() string { return "dump" }
+func (bigTool) Definition() core.ToolDefinition {
+	return core.ToolDefinition{Name: "dump", Description: "dump"}
+}
+func (bigTool) ExecuteRaw(_ context.Context, _ json.RawMessage) (core.ToolResult, error) {
+	return core.TextResult("HEAD" + strings.Repeat("x", 10_000) + "TAIL"), nil
+}
+
+func TestTruncateToolResult_CapsModelSink(t *testing.T) {
+	var lastReq core.ChatRequest
+	provider := &mockProvider{
+		name: "test",
+		responses: []core.ChatResponse{
+			{ToolCalls: []core.ToolCall{{ID: "1", Name: "dump", Args: json.RawMessage(`{}`)}}},
+			{Content: "done"},
+		},
+		onChat: func(r *core.ChatRequest) { lastReq = *r },
+	}
+	a := New("t", "d", provider,
+		WithTools(bigTool{}),
+		WithToolConfig(ToolConfig{
+			TransformMatchers: []TransformMatcher{{
+				Match:     func(string) bool { return true },
+				Transform: core.ToolTransform{Model: &core.SinkTransform{Result: TruncateToolResult(100)}},
+			}},
+		}),
+	)
+	if _, err := a.Execute(context.Background(), AgentTask{Input: "go"}); err != nil {
+		t.Fatal(err)
+	}
+
+	var toolMsgs []string
+	for _, m := range lastReq.Messages {
+		if m.Role == core.RoleTool {
+			toolMsgs = append(toolMsgs, m.Content)
+		}
+	}
+	if len(toolMsgs) != 1 {
+		t.Fatalf("tool messages = %d, want 1", len(toolMsgs))
+	}
+	got := toolMsgs[0]
+	if !strings.HasPrefix(got, "HEAD") || !strings.HasSuffix(got, "TAIL") {
+		t.Errorf("truncated result lost its head or tail: %.40q…", got)
+	}
+	if !strings.Contains(got, "[... 9947 characters truncated ...]") {
+		t.Errorf("missing truncation marker in %q", got)
+	}
+	if len(got) != 100 {
+		t.Errorf("len = %d, want 100 with the marker counted", len(got))
+	}
+}
@@ -62,6 +62,7 @@ func buildStepTrace(tc core.ToolCall, res toolExecResult) StepTrace {
 		// json.RawMessage here used to copy the full payload per step — the
 		// dominant allocation for large tool results.
 		RawOutput: res.content,
+		IsError:   res.isError,
 		Usage:     res.usage,
 		Duration:  res.duration,
 	}
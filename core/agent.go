@@ -3,6 +3,7 @@ package core
 import (
 	"context"
 	"encoding/json"
+	"strings"
 	"time"
 )
 
@@ -205,6 +206,10 @@ type StepTrace struct {
 	// validation, and the string→[]byte conversion copied the full payload
 	// per step. A string shares the tool result's immutable backing memory.
 	RawOutput string `json:"raw_output,omitempty"`
+	// IsError reports that the step failed. Output and RawOutput then hold
+	// the error text (for tool calls, the "error: ..." message the model
+	// saw); check this flag instead of parsing it.
+	IsError bool `json:"is_error,omitempty"`
 	// Usage is the token usage for this individual step.
 	Usage Usage `json:"usage"`
 	// Duration is the wall-clock time for this step.
@@ -289,7 +294,8 @@ func (r AgentResult) ToolCalls() []ToolCall {
 // Content contains the untruncated content the tool returned (sourced from
 // StepTrace.RawOutput when populated), safe to json.Unmarshal when the tool
 // emitted JSON. Falls back to StepTrace.Output for traces built externally
-// without RawOutput.
+// without RawOutput. For a failed step (StepTrace.IsError) the message is
+// returned in Error, without the "error: " prefix, and Content is empty.
 func (r AgentResult) ToolResults() []ToolResult {
 	if len(r.Steps) == 0 {
 		return nil
@@ -300,6 +306,10 @@ func (r AgentResult) ToolResults() []ToolResult {
 		if content == "" {
 			content = s.Output
 		}
+		if s.IsError {
+			out = append(out, ToolResult{Error: strings.TrimPrefix(content, "error: ")})
+			continue
+		}
 		out = append(out, ToolResult{Content: content})
 	}
 	return out
@@ -12,8 +12,9 @@ var runUsageKey runUsageKeyType
 // runUsage is a run-scoped, per-model usage accumulator. Concurrency-guarded
 // because the loop goroutine writes while processors read.
 type runUsage struct {
-	mu    sync.Mutex
-	byMod map[string]Usage
+	mu         sync.Mutex
+	byMod      map[string]Usage
+	embedCalls int
 }
 
 // WithRunUsage returns a context carrying a fresh per-model usage accumulator.
@@ -55,3 +56,27 @@ func RunUsageByModel(ctx context.Context) (map[string]Usage, bool) {
 	ru.mu.Unlock()
 	return out, true
 }
+
+// AddRunEmbedCall counts one EmbeddingProvider.Embed call against the run.
+// No-op if ctx has no accumulator.
+func AddRunEmbedCall(ctx context.Context) {
+	ru, _ := ctx.Value(runUsageKey).(*runUsage)
+	if ru == nil {
+		return
+	}
+	ru.mu.Lock()
+	ru.embedCalls++
+	ru.mu.Unlock()
+}
+
+// RunEmbedCalls returns the number of Embed calls counted against the run so
+// far, or 0 if ctx has no accumulator.
+func RunEmbedCalls(ctx context.Context) int {
+	ru, _ := ctx.Value(runUsageKey).(*runUsage)
+	if ru == nil {
+		return 0
+	}
+	ru.mu.Lock()
+	defer ru.mu.Unlock()
+	return ru.embedCalls
+}
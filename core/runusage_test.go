@@ -30,3 +30,17 @@ func TestRunUsageAbsent(t *testing.T) {
 	// AddRunUsage on a bare context must be a safe no-op.
 	AddRunUsage(context.Background(), "x", Usage{InputTokens: 1})
 }
+
+func TestRunEmbedCalls(t *testing.T) {
+	ctx := WithRunUsage(context.Background())
+	AddRunEmbedCall(ctx)
+	AddRunEmbedCall(ctx)
+	if n := RunEmbedCalls(ctx); n != 2 {
+		t.Errorf("RunEmbedCalls = %d, want 2", n)
+	}
+	// A bare context counts nothing and must not panic.
+	AddRunEmbedCall(context.Background())
+	if n := RunEmbedCalls(context.Background()); n != 0 {
+		t.Errorf("RunEmbedCalls on a bare context = %d, want 0", n)
+	}
+}
@@ -0,0 +1,312 @@
+package core
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"slices"
+	"strings"
+	"unicode"
+)
+
+// ScheduledToolCall is one entry of the JSON array stored in
+// ScheduledAction.ToolCalls.
+type ScheduledToolCall struct {
+	Tool   string          `json:"tool"`
+	Params json.RawMessage `json:"params,omitempty"`
+}
+
+// ErrScheduledToolNotAllowed is returned (wrapped) by a store from
+// RestrictScheduledTools when an action calls a tool outside its allowlist.
+var ErrScheduledToolNotAllowed = errors.New("tool not allowed in scheduled actions")
+
+// ErrDuplicateScheduledAction is returned (wrapped) by
+// CreateScheduledActionUnique when an enabled action with the same
+// description and schedule already exists.
+var ErrDuplicateScheduledAction = errors.New("duplicate scheduled action")
+
+// ParseScheduledToolCalls decodes ScheduledAction.ToolCalls. An empty string
+// decodes to no calls.
+func ParseScheduledToolCalls(toolCalls string) ([]ScheduledToolCall, error) {
+	if strings.TrimSpace(toolCalls) == "" {
+		return nil, nil
+	}
+	var calls []ScheduledToolCall
+	if err := json.Unmarshal([]byte(toolCalls), &calls); err != nil {
+		return nil, fmt.Errorf("parse scheduled tool calls: %w", err)
+	}
+	return calls, nil
+}
+
+// RestrictScheduledTools wraps s so that creating or updating a scheduled
+// action fails when its ToolCalls name a tool outside allowed. A scheduled
+// action runs unattended, so destructive or interactive tools (shell, file
+// writes, ask_user) should never be reachable from one:
+//
+//	sas = core.RestrictScheduledTools(sas, "web_search", "knowledge_search")
+//
+// The error wraps ErrScheduledToolNotAllowed and names the denied tool and
+// the allowed set, so it can be shown to the user who asked for the
+// schedule. Other methods pass through.
+func RestrictScheduledTools(s ScheduledActionStore, allowed ...string) ScheduledActionStore {
+	return &restrictedScheduledActionStore{ScheduledActionStore: s, allowed: allowed}
+}
+
+// restrictedScheduledActionStore is the store returned by
+// RestrictScheduledTools.
+type restrictedScheduledActionStore struct {
+	ScheduledActionStore
+	allowed []string
+}
+
+func (r *restrictedScheduledActionStore) CreateScheduledAction(ctx context.Context, action ScheduledAction) error {
+	if err := r.check(action); err != nil {
+		return err
+	}
+	return r.ScheduledActionStore.CreateScheduledAction(ctx, action)
+}
+
+func (r *restrictedScheduledActionStore) UpdateScheduledAction(ctx context.Context, action ScheduledAction) error {
+	if err := r.check(action); err != nil {
+		return err
+	}
+	return r.ScheduledActionStore.UpdateScheduledAction(ctx, action)
+}
+
+// check rejects action when any of its tool calls is outside the allowlist.
+func (r *restrictedScheduledActionStore) check(action ScheduledAction) error {
+	calls, err := ParseScheduledToolCalls(action.ToolCalls)
+	if err != nil {
+		return err
+	}
+	for _, c := range calls {
+		if !slices.Contains(r.allowed, c.Tool) {
+			return fmt.Errorf("scheduled action %q: %w: %q (allowed: %s)",
+				action.Description, ErrScheduledToolNotAllowed, c.Tool, strings.Join(r.allowed, ", "))
+		}
+	}
+	return nil
+}
+
+// FindDuplicateScheduledAction returns the enabled action in s that
+// duplicates action, or nil when there is none. Two actions are duplicates
+// when their descriptions match ignoring case, punctuation, and runs of
+// whitespace, and their schedules match ignoring case and whitespace only,
+// so "Morning briefing!" at "07:00  daily" duplicates "morning briefing" at
+// "07:00 daily" while cron schedules "0 */5 * * *" and "0 5 * * *" stay
+// distinct. An action never duplicates itself (same ID).
+//
+// Use it for advisory checks (warn, or offer to update the existing action);
+// CreateScheduledActionUnique rejects duplicates outright.
+func FindDuplicateScheduledAction(ctx context.Context, s ScheduledActionStore, action ScheduledAction) (*ScheduledAction, error) {
+	// Why: ListScheduledActionsByDescription matches a raw substring, which
+	// misses variants that differ only in case or punctuation.
+	actions, err := s.ListScheduledActions(ctx)
+	if err != nil {
+		return nil, err
+	}
+	for i := range actions {
+		a := &actions[i]
+		if !a.Enabled || a.ID == action.ID {
+			continue
+		}
+		if sameScheduledAction(*a, action) {
+			return a, nil
+		}
+	}
+	return nil, nil
+}
+
+// CreateScheduledActionUnique creates action unless FindDuplicateScheduledAction
+// reports an enabled duplicate, in which case it creates nothing and returns
+// an error wrapping ErrDuplicateScheduledAction that names the existing
+// action's ID.
+func CreateScheduledActionUnique(ctx context.Context, s ScheduledActionStore, action ScheduledAction) error {
+	dup, err := FindDuplicateScheduledAction(ctx, s, action)
+	if err != nil {
+		return err
+	}
+	if dup != nil {
+		return fmt.Errorf("%w: %q (%s) already exists as %s", ErrDuplicateScheduledAction, dup.Description, dup.Schedule, dup.ID)
+	}
+	return s.CreateScheduledAction(ctx, action)
+}
+
+// scheduledActionFile is one action in the ExportScheduledActions format.
+// ToolCalls is embedded as JSON rather than a quoted string so the file
+// stays readable and editable by hand.
+type scheduledActionFile struct {
+	ID              string          `json:"id,omitempty"`
+	Description     string          `json:"description"`
+	Schedule        string          `json:"schedule"`
+	ToolCalls       json.RawMessage `json:"tool_calls,omitempty"`
+	SynthesisPrompt string          `json:"synthesis_prompt,omitempty"`
+	Enabled         bool            `json:"enabled"`
+	SkillID         string          `json:"skill_id,omitempty"`
+}
+
+// ExportScheduledActions writes every action in s to w as an indented JSON
+// array holding each action's ID, description, schedule, tool calls,
+// synthesis prompt, enabled flag, and skill ID. Run state (NextRun,
+// CreatedAt) is left out. ImportScheduledActions reads the format back.
+func ExportScheduledActions(ctx context.Context, s ScheduledActionStore, w io.Writer) error {
+	actions, err := s.ListScheduledActions(ctx)
+	if err != nil {
+		return err
+	}
+	out := make([]scheduledActionFile, 0, len(actions))
+	for _, a := range actions {
+		f := scheduledActionFile{
+			ID:              a.ID,
+			Description:     a.Description,
+			Schedule:        a.Schedule,
+			SynthesisPrompt: a.SynthesisPrompt,
+			Enabled:         a.Enabled,
+			SkillID:         a.SkillID,
+		}
+		if strings.TrimSpace(a.ToolCalls) != "" {
+			if !json.Valid([]byte(a.ToolCalls)) {
+				return fmt.Errorf("export scheduled action %s: tool_calls is not valid JSON", a.ID)
+			}
+			f.ToolCalls = json.RawMessage(a.ToolCalls)
+		}
+		out = append(out, f)
+	}
+	enc := json.NewEncoder(w)
+	enc.SetIndent("", "  ")
+	return enc.Encode(out)
+}
+
+// ScheduledImportResult reports what ImportScheduledActions changed.
+type ScheduledImportResult struct {
+	Created int
+	Updated int
+}
+
+// ImportScheduledActions reads actions in the ExportScheduledActions format
+// from r and upserts them into s. An action matches an existing one by ID
+// or, when the ID is empty or unknown, by description and schedule (compared
+// like FindDuplicateScheduledAction does); a match is updated, anything else
+// is created with a new ID.
+//
+// nextRun validates each schedule and returns the action's next run time;
+// the framework does not parse schedules itself. tool_calls must be a JSON
+// array, and no two entries may share an ID or a description and schedule.
+// The whole file is parsed and validated before anything is written, so a
+// bad entry leaves s untouched.
+func ImportScheduledActions(ctx context.Context, s ScheduledActionStore, r io.Reader, nextRun func(schedule string) (int64, error)) (ScheduledImportResult, error) {
+	if nextRun == nil {
+		return ScheduledImportResult{}, errors.New("import scheduled actions: nextRun is required")
+	}
+	var in []scheduledActionFile
+	if err := json.NewDecoder(r).Decode(&in); err != nil {
+		return ScheduledImportResult{}, fmt.Errorf("import scheduled actions: %w", err)
+	}
+	actions := make([]ScheduledAction, len(in))
+	for i, f := range in {
+		if strings.TrimSpace(f.Description) == "" {
+			return ScheduledImportResult{}, fmt.Errorf("import scheduled actions: entry %d has no description", i+1)
+		}
+		next, err := nextRun(f.Schedule)
+		if err != nil {
+			return ScheduledImportResult{}, fmt.Errorf("import scheduled actions: %q: invalid schedule %q: %w", f.Description, f.Schedule, err)
+		}
+		// Why: the file holds tool calls indented for editing; store them
+		// compact, the way they were before export.
+		var toolCalls bytes.Buffer
+		if len(f.ToolCalls) > 0 {
+			if err := json.Compact(&toolCalls, f.ToolCalls); err != nil {
+				return ScheduledImportResult{}, fmt.Errorf("import scheduled actions: %q: tool_calls: %w", f.Description, err)
+			}
+			if _, err := ParseScheduledToolCalls(toolCalls.String()); err != nil {
+				return ScheduledImportResult{}, fmt.Errorf("import scheduled actions: %q: tool_calls must be an array of {\"tool\", \"params\"} objects: %w", f.Description, err)
+			}
+		}
+		actions[i] = ScheduledAction{
+			ID:              f.ID,
+			Description:     f.Description,
+			Schedule:        f.Schedule,
+			ToolCalls:       toolCalls.String(),
+			SynthesisPrompt: f.SynthesisPrompt,
+			NextRun:         next,
+			Enabled:         f.Enabled,
+			SkillID:         f.SkillID,
+		}
+		// Why: a later entry matching an earlier one would silently
+		// overwrite it on upsert.
+		for j := range i {
+			if (f.ID != "" && actions[j].ID == f.ID) || sameScheduledAction(actions[j], actions[i]) {
+				return ScheduledImportResult{}, fmt.Errorf("import scheduled actions: entries %d and %d describe the same action %q", j+1, i+1, f.Description)
+			}
+		}
+	}
+
+	existing, err := s.ListScheduledActions(ctx)
+	if err != nil {
+		return ScheduledImportResult{}, err
+	}
+	var res ScheduledImportResult
+	for _, a := range actions {
+		if prior := matchScheduledAction(existing, a); prior != nil {
+			a.ID, a.CreatedAt = prior.ID, prior.CreatedAt
+			if err := s.UpdateScheduledAction(ctx, a); err != nil {
+				return res, err
+			}
+			res.Updated++
+			continue
+		}
+		a.ID, a.CreatedAt = NewID(), NowUnix()
+		if err := s.CreateScheduledAction(ctx, a); err != nil {
+			return res, err
+		}
+		existing = append(existing, a)
+		res.Created++
+	}
+	return res, nil
+}
+
+// matchScheduledAction returns the action in existing that a matches by ID,
+// else by normalized description and schedule, or nil.
+func matchScheduledAction(existing []ScheduledAction, a ScheduledAction) *ScheduledAction {
+	if a.ID != "" {
+		for i := range existing {
+			if existing[i].ID == a.ID {
+				return &existing[i]
+			}
+		}
+	}
+	for i := range existing {
+		if sameScheduledAction(existing[i], a) {
+			return &existing[i]
+		}
+	}
+	return nil
+}
+
+// sameScheduledAction reports whether a and b have the same description and
+// schedule after normalization.
+func sameScheduledAction(a, b ScheduledAction) bool {
+	return normalizeDescription(a.Description) == normalizeDescription(b.Description) &&
+		normalizeSchedule(a.Schedule) == normalizeSchedule(b.Schedule)
+}
+
+// normalizeDescription lowercases s, drops punctuation, and collapses
+// whitespace, for comparing descriptions.
+func normalizeDescription(s string) string {
+	s = strings.Map(func(r rune) rune {
+		if unicode.IsPunct(r) {
+			return -1
+		}
+		return unicode.ToLower(r)
+	}, s)
+	return strings.Join(strings.Fields(s), " ")
+}
+
+// normalizeSchedule lowercases s and collapses whitespace. Punctuation is
+// kept: in cron syntax "*/5", "1-5", and "1,5" all mean different things.
+func normalizeSchedule(s string) string {
+	return strings.Join(strings.Fields(strings.ToLower(s)), " ")
+}
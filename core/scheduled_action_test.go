@@ -0,0 +1,257 @@
+package core
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"strings"
+	"testing"
+)
+
+// memScheduledActionStore is an in-memory ScheduledActionStore for tests.
+type memScheduledActionStore struct {
+	actions []ScheduledAction
+}
+
+func (m *memScheduledActionStore) CreateScheduledAction(_ context.Context, a ScheduledAction) error {
+	m.actions = append(m.actions, a)
+	return nil
+}
+
+func (m *memScheduledActionStore) ListScheduledActions(context.Context) ([]ScheduledAction, error) {
+	return append([]ScheduledAction(nil), m.actions...), nil
+}
+
+func (m *memScheduledActionStore) GetDueScheduledActions(_ context.Context, now int64) ([]ScheduledAction, error) {
+	var out []ScheduledAction
+	for _, a := range m.actions {
+		if a.Enabled && a.NextRun <= now {
+			out = append(out, a)
+		}
+	}
+	return out, nil
+}
+
+func (m *memScheduledActionStore) UpdateScheduledAction(_ context.Context, a ScheduledAction) error {
+	for i := range m.actions {
+		if m.actions[i].ID == a.ID {
+			m.actions[i] = a
+		}
+	}
+	return nil
+}
+
+func (m *memScheduledActionStore) UpdateScheduledActionEnabled(_ context.Context, id string, enabled bool) error {
+	for i := range m.actions {
+		if m.actions[i].ID == id {
+			m.actions[i].Enabled = enabled
+		}
+	}
+	return nil
+}
+
+func (m *memScheduledActionStore) DeleteScheduledAction(_ context.Context, id string) error {
+	for i := range m.actions {
+		if m.actions[i].ID == id {
+			m.actions = append(m.actions[:i], m.actions[i+1:]...)
+			return nil
+		}
+	}
+	return nil
+}
+
+func (m *memScheduledActionStore) DeleteAllScheduledActions(context.Context) (int, error) {
+	n := len(m.actions)
+	m.actions = nil
+	return n, nil
+}
+
+func (m *memScheduledActionStore) ListScheduledActionsByDescription(_ context.Context, pattern string) ([]ScheduledAction, error) {
+	var out []ScheduledAction
+	for _, a := range m.actions {
+		if strings.Contains(strings.ToLower(a.Description), strings.ToLower(pattern)) {
+			out = append(out, a)
+		}
+	}
+	return out, nil
+}
+
+func TestRestrictScheduledTools(t *testing.T) {
+	inner := &memScheduledActionStore{}
+	s := RestrictScheduledTools(inner, "web_search", "knowledge_search")
+	ctx := context.Background()
+
+	ok := ScheduledAction{ID: "a1", Description: "morning news", ToolCalls: `[{"tool":"web_search","params":{"query":"news"}}]`}
+	if err := s.CreateScheduledAction(ctx, ok); err != nil {
+		t.Fatalf("allowed tool rejected: %v", err)
+	}
+
+	denied := ScheduledAction{ID: "a2", Description: "cleanup", ToolCalls: `[{"tool":"web_search"},{"tool":"shell","params":{"command":"rm -rf /tmp/x"}}]`}
+	err := s.CreateScheduledAction(ctx, denied)
+	if !errors.Is(err, ErrScheduledToolNotAllowed) {
+		t.Fatalf("err = %v, want ErrScheduledToolNotAllowed", err)
+	}
+	if !strings.Contains(err.Error(), `"shell"`) {
+		t.Errorf("error %q does not name the denied tool", err)
+	}
+	if len(inner.actions) != 1 {
+		t.Fatalf("stored %d actions, want only the allowed one", len(inner.actions))
+	}
+
+	ok.ToolCalls = `[{"tool":"ask_user"}]`
+	if err := s.UpdateScheduledAction(ctx, ok); !errors.Is(err, ErrScheduledToolNotAllowed) {
+		t.Errorf("update err = %v, want ErrScheduledToolNotAllowed", err)
+	}
+	if err := s.CreateScheduledAction(ctx, ScheduledAction{ID: "a3", ToolCalls: `not json`}); err == nil {
+		t.Error("expected malformed tool calls to be rejected")
+	}
+}
+
+func TestCreateScheduledActionUnique(t *testing.T) {
+	s := &memScheduledActionStore{actions: []ScheduledAction{
+		{ID: "a1", Description: "Morning briefing", Schedule: "07:00 daily", Enabled: true},
+		{ID: "a2", Description: "Weekly report", Schedule: "monday 09:00", Enabled: false},
+	}}
+	ctx := context.Background()
+
+	near := ScheduledAction{ID: "a3", Description: "  morning briefing! ", Schedule: "07:00  Daily", Enabled: true}
+	dup, err := FindDuplicateScheduledAction(ctx, s, near)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if dup == nil || dup.ID != "a1" {
+		t.Fatalf("dup = %+v, want a1", dup)
+	}
+	err = CreateScheduledActionUnique(ctx, s, near)
+	if !errors.Is(err, ErrDuplicateScheduledAction) || !strings.Contains(err.Error(), "a1") {
+		t.Fatalf("err = %v, want ErrDuplicateScheduledAction naming a1", err)
+	}
+	if len(s.actions) != 2 {
+		t.Fatalf("stored %d actions, want the duplicate rejected", len(s.actions))
+	}
+
+	// Different schedule, or a duplicate of a disabled action: not duplicates.
+	for _, a := range []ScheduledAction{
+		{ID: "a4", Description: "Morning briefing", Schedule: "08:00 daily", Enabled: true},
+		{ID: "a5", Description: "weekly report", Schedule: "Monday 09:00", Enabled: true},
+	} {
+		if err := CreateScheduledActionUnique(ctx, s, a); err != nil {
+			t.Errorf("%s: unexpected error %v", a.ID, err)
+		}
+	}
+	if len(s.actions) != 4 {
+		t.Errorf("stored %d actions, want 4", len(s.actions))
+	}
+
+	// Cron punctuation is significant in schedules.
+	cron := &memScheduledActionStore{actions: []ScheduledAction{
+		{ID: "c1", Description: "Poll feed", Schedule: "0 */5 * * *", Enabled: true},
+		{ID: "c2", Description: "Weekday digest", Schedule: "0 9 * * 1-5", Enabled: true},
+	}}
+	for _, a := range []ScheduledAction{
+		{ID: "c3", Description: "Poll feed", Schedule: "0 5 * * *", Enabled: true},
+		{ID: "c4", Description: "Weekday digest", Schedule: "0 9 * * 15", Enabled: true},
+		{ID: "c5", Description: "Weekday digest", Schedule: "0 9 * * 1,5", Enabled: true},
+	} {
+		if dup, err := FindDuplicateScheduledAction(ctx, cron, a); err != nil || dup != nil {
+			t.Errorf("%q: dup = %+v, err = %v; want no duplicate", a.Schedule, dup, err)
+		}
+	}
+	if dup, _ := FindDuplicateScheduledAction(ctx, cron, ScheduledAction{Description: "poll feed", Schedule: "0  */5 * * *"}); dup == nil || dup.ID != "c1" {
+		t.Errorf("dup = %+v, want c1 for the same cron schedule", dup)
+	}
+}
+
+func TestExportImportScheduledActions_RoundTrip(t *testing.T) {
+	ctx := context.Background()
+	src := &memScheduledActionStore{actions: []ScheduledAction{
+		{ID: "a1", Description: "Morning briefing", Schedule: "07:00 daily", ToolCalls: `[{"tool":"web_search","params":{"query":"news"}}]`, SynthesisPrompt: "Summarize", NextRun: 111, Enabled: true, CreatedAt: 5},
+		{ID: "a2", Description: "Weekly report", Schedule: "monday 09:00", Enabled: false, SkillID: "reports"},
+	}}
+	nextRun := func(schedule string) (int64, error) {
+		if schedule == "" {
+			return 0, errors.New("empty schedule")
+		}
+		return 999, nil
+	}
+
+	var buf bytes.Buffer
+	if err := ExportScheduledActions(ctx, src, &buf); err != nil {
+		t.Fatal(err)
+	}
+	if !strings.Contains(buf.String(), `"tool": "web_search"`) {
+		t.Errorf("tool calls not exported as editable JSON:\n%s", buf.String())
+	}
+
+	dst := &memScheduledActionStore{}
+	res, err := ImportScheduledActions(ctx, dst, bytes.NewReader(buf.Bytes()), nextRun)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.Created != 2 || res.Updated != 0 || len(dst.actions) != 2 {
+		t.Fatalf("result = %+v with %d actions, want 2 created", res, len(dst.actions))
+	}
+	for i, got := range dst.actions {
+		want := src.actions[i]
+		if got.Description != want.Description || got.Schedule != want.Schedule ||
+			got.SynthesisPrompt != want.SynthesisPrompt || got.Enabled != want.Enabled || got.SkillID != want.SkillID {
+			t.Errorf("action %d = %+v, want %+v", i, got, want)
+		}
+		if want.ToolCalls != "" && got.ToolCalls != want.ToolCalls {
+			t.Errorf("action %d tool calls = %s, want %s", i, got.ToolCalls, want.ToolCalls)
+		}
+		if got.NextRun != 999 || got.ID == "" {
+			t.Errorf("action %d: NextRun = %d, ID = %q; want recomputed run and a new ID", i, got.NextRun, got.ID)
+		}
+	}
+
+	// Re-importing into the source updates in place, matching by ID.
+	res, err = ImportScheduledActions(ctx, src, bytes.NewReader(buf.Bytes()), nextRun)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.Created != 0 || res.Updated != 2 || len(src.actions) != 2 || src.actions[0].CreatedAt != 5 {
+		t.Errorf("result = %+v, actions = %+v; want 2 updated in place", res, src.actions)
+	}
+
+	// Without an ID, an entry matches by description and schedule; the same
+	// description on another schedule is a separate action.
+	edited := `[
+		{"description": "morning briefing!", "schedule": "07:00 daily", "synthesis_prompt": "Be brief", "enabled": true},
+		{"description": "Morning briefing", "schedule": "09:00 saturday", "enabled": true}
+	]`
+	res, err = ImportScheduledActions(ctx, src, strings.NewReader(edited), nextRun)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.Updated != 1 || res.Created != 1 || src.actions[0].SynthesisPrompt != "Be brief" || src.actions[0].Schedule != "07:00 daily" {
+		t.Errorf("result = %+v, actions = %+v; want a1 updated and a Saturday action created", res, src.actions)
+	}
+	if len(src.actions) != 3 {
+		t.Fatalf("stored %d actions, want 3", len(src.actions))
+	}
+
+	// Two entries for the same action, or tool calls that aren't an array,
+	// reject the file.
+	for name, file := range map[string]string{
+		"duplicate entries": `[{"description": "Poll", "schedule": "0 */5 * * *"}, {"description": "poll", "schedule": "0 */5 * * *"}]`,
+		"tool_calls object": `[{"description": "Poll", "schedule": "0 */5 * * *", "tool_calls": {"tool": "web_search"}}]`,
+		"tool_calls string": `[{"description": "Poll", "schedule": "0 */5 * * *", "tool_calls": "web_search"}]`,
+	} {
+		if _, err := ImportScheduledActions(ctx, src, strings.NewReader(file), nextRun); err == nil {
+			t.Errorf("%s: expected the file to be rejected", name)
+		}
+	}
+	if len(src.actions) != 3 {
+		t.Fatalf("stored %d actions after rejected imports, want 3", len(src.actions))
+	}
+
+	// An invalid schedule rejects the whole file.
+	bad := `[{"description": "new one", "schedule": "07:00 daily"}, {"description": "broken", "schedule": ""}]`
+	if _, err := ImportScheduledActions(ctx, src, strings.NewReader(bad), nextRun); err == nil {
+		t.Fatal("expected an invalid schedule to be rejected")
+	}
+	if len(src.actions) != 3 {
+		t.Errorf("stored %d actions after a rejected import, want 3", len(src.actions))
+	}
+}
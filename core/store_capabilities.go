@@ -46,6 +46,33 @@ type DocumentMetaLister interface {
 	ListDocumentMeta(ctx context.Context, limit int) ([]Document, error)
 }
 
+// DocumentStats is a document's footprint in the knowledge base. Document is
+// returned without its Content. ContentBytes is the size of the document
+// text plus its chunks' text; embeddings and indexes are not counted.
+type DocumentStats struct {
+	Document
+	ChunkCount   int   `json:"chunk_count"`
+	ContentBytes int64 `json:"content_bytes"`
+}
+
+// DocumentStatsLister is an optional Store capability that reports how much
+// of the knowledge base each document takes up, so large or stale documents
+// can be found and removed with DeleteDocument.
+type DocumentStatsLister interface {
+	// ListDocumentStats returns documents ordered by ChunkCount, largest
+	// first. limit <= 0 returns all documents.
+	ListDocumentStats(ctx context.Context, limit int) ([]DocumentStats, error)
+}
+
+// DocumentSourceFinder is an optional Store capability that looks up a
+// document by its Source. The ingest pipeline uses it to replace, rather than
+// duplicate, a document that is ingested again (see ingest.WithReplaceBySource).
+type DocumentSourceFinder interface {
+	// FindDocumentBySource returns the newest document whose Source equals
+	// source, without its Content. It returns nil, nil when none exists.
+	FindDocumentBySource(ctx context.Context, source string) (*Document, error)
+}
+
 // ScheduledActionStore is an optional Store capability for scheduled actions.
 // Store implementations that support scheduling can implement this interface;
 // callers discover it via type assertion.
@@ -224,8 +224,9 @@ type StreamEvent struct {
 	// Set on agent-finish and tool-call-result events. Zero value otherwise.
 	Duration time.Duration `json:"duration,omitempty"`
 	// IsError reports that the step this event describes failed. Set on
+	// tool-call-result events when the tool returned an error, and on
 	// agent-finish events when the delegated subagent returned an error
-	// (Content then carries the "error: ..." text the router sees). False on
+	// (Content then carries the "error: ..." text the model sees). False on
 	// success and on all other event types.
 	IsError bool `json:"is_error,omitempty"`
 	// Agent is the name of the delegated subagent whose run produced this
@@ -13,6 +13,8 @@
 //   - KeywordGuard:      keyword and regex blocklist for user messages.
 //   - MaxToolCallsGuard: silently trims excess tool calls per LLM turn
 //     (graceful degradation, no halt).
+//   - LoopGuard:         withholds tools and asks for a summary once the
+//     same tool call repeats (breaks tool-call loops).
 //
 // Basic usage:
 //
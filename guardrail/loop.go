@@ -0,0 +1,115 @@
+package guardrail
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"log/slog"
+
+	"github.com/nevindra/oasis/core"
+)
+
+// defaultLoopMessage is appended when a LoopGuard breaks a tool-call loop.
+const defaultLoopMessage = "You are repeating the same tool call without making progress. " +
+	"Stop calling tools. Tell the user what you accomplished, what remains " +
+	"unfinished, and why."
+
+// LoopGuard ends a run that keeps issuing the same tool call. Before each
+// LLM call it counts identical calls (same name and arguments) made since the
+// latest user message; once any call has been made maxRepeats times it
+// withholds the tools from the request and asks the model to summarize what
+// it accomplished and what remains. The run then finishes with a useful
+// answer instead of spending the rest of its iteration budget in the loop.
+// Stateless and safe for concurrent use.
+type LoopGuard struct {
+	maxRepeats int
+	message    string
+	logger     *slog.Logger
+}
+
+// LoopOption configures a LoopGuard.
+type LoopOption func(*LoopGuard)
+
+// NewLoopGuard returns a guard that breaks the loop once a tool call has been
+// repeated maxRepeats times. A maxRepeats below 2 defaults to 3.
+func NewLoopGuard(maxRepeats int, opts ...LoopOption) *LoopGuard {
+	if maxRepeats < 2 {
+		maxRepeats = 3
+	}
+	g := &LoopGuard{maxRepeats: maxRepeats, message: defaultLoopMessage}
+	for _, opt := range opts {
+		opt(g)
+	}
+	if g.logger == nil {
+		g.logger = nopLogger
+	}
+	return g
+}
+
+// LoopMessage replaces the instruction appended when a loop is detected.
+func LoopMessage(msg string) LoopOption {
+	return func(g *LoopGuard) { g.message = msg }
+}
+
+// LoopLogger sets the guard's logger.
+func LoopLogger(l *slog.Logger) LoopOption {
+	return func(g *LoopGuard) { g.logger = l }
+}
+
+// PreLLM withholds tools and requests a summary when the current run has
+// repeated a tool call maxRepeats times.
+func (g *LoopGuard) PreLLM(ctx context.Context, req *core.ChatRequest) error {
+	if len(req.Tools) == 0 {
+		return nil
+	}
+	name, count := mostRepeatedCall(req.Messages[lastUserIndex(req.Messages)+1:])
+	if count < g.maxRepeats {
+		return nil
+	}
+	g.logger.WarnContext(ctx, "tool-call loop detected — withholding tools",
+		"tool", name, "repeats", count, "max_repeats", g.maxRepeats)
+
+	// Why: req.Messages aliases the run loop's history slice; appending in
+	// place could write into its spare capacity.
+	msgs := make([]core.ChatMessage, len(req.Messages), len(req.Messages)+1)
+	copy(msgs, req.Messages)
+	req.Messages = append(msgs, core.UserMessage(g.message))
+	req.Tools = nil
+	return nil
+}
+
+// mostRepeatedCall returns the tool name and count of the most frequent
+// identical tool call among msgs' assistant messages.
+func mostRepeatedCall(msgs []core.ChatMessage) (string, int) {
+	counts := make(map[string]int)
+	var (
+		top  string
+		best int
+	)
+	for _, m := range msgs {
+		if m.Role != core.RoleAssistant {
+			continue
+		}
+		for _, tc := range m.ToolCalls {
+			key := tc.Name + "\x00" + canonicalArgs(tc.Args)
+			counts[key]++
+			if counts[key] > best {
+				top, best = tc.Name, counts[key]
+			}
+		}
+	}
+	return top, best
+}
+
+// canonicalArgs compacts JSON arguments so whitespace differences don't hide
+// a repeat. Invalid JSON is compared verbatim.
+func canonicalArgs(args json.RawMessage) string {
+	var buf bytes.Buffer
+	if err := json.Compact(&buf, args); err != nil {
+		return string(args)
+	}
+	return buf.String()
+}
+
+// compile-time check
+var _ core.PreProcessor = (*LoopGuard)(nil)
@@ -0,0 +1,80 @@
+package guardrail
+
+import (
+	"context"
+	"encoding/json"
+	"testing"
+
+	"github.com/nevindra/oasis/core"
+)
+
+// loopRequest builds a request whose current run made the given tool calls,
+// one per assistant message.
+func loopRequest(calls ...core.ToolCall) *core.ChatRequest {
+	msgs := []core.ChatMessage{core.SystemMessage("sys"), core.UserMessage("find the price")}
+	for _, tc := range calls {
+		msgs = append(msgs,
+			core.ChatMessage{Role: core.RoleAssistant, ToolCalls: []core.ToolCall{tc}},
+			core.ChatMessage{Role: core.RoleTool, ToolCallID: tc.ID, Content: "no results"})
+	}
+	return &core.ChatRequest{
+		Messages: msgs,
+		Tools:    []core.ToolDefinition{{Name: "web_search"}},
+	}
+}
+
+func searchCall(id, args string) core.ToolCall {
+	return core.ToolCall{ID: id, Name: "web_search", Args: json.RawMessage(args)}
+}
+
+func TestLoopGuardBreaksRepeatedCall(t *testing.T) {
+	g := NewLoopGuard(3)
+	req := loopRequest(
+		searchCall("1", `{"q":"price"}`),
+		searchCall("2", `{ "q": "price" }`), // whitespace differs, same call
+		searchCall("3", `{"q":"price"}`),
+	)
+	before := len(req.Messages)
+	history := req.Messages
+
+	if err := g.PreLLM(context.Background(), req); err != nil {
+		t.Fatal(err)
+	}
+	if req.Tools != nil {
+		t.Error("expected tools to be withheld")
+	}
+	if len(req.Messages) != before+1 || req.Messages[before].Role != core.RoleUser {
+		t.Fatalf("expected a summary instruction appended, got %d messages", len(req.Messages))
+	}
+	if &req.Messages[0] == &history[0] {
+		t.Error("guard appended into the caller's history slice")
+	}
+}
+
+func TestLoopGuardAllowsVariedCalls(t *testing.T) {
+	g := NewLoopGuard(3)
+	req := loopRequest(
+		searchCall("1", `{"q":"price"}`),
+		searchCall("2", `{"q":"price 2026"}`),
+		searchCall("3", `{"q":"price"}`),
+	)
+	if err := g.PreLLM(context.Background(), req); err != nil {
+		t.Fatal(err)
+	}
+	if req.Tools == nil {
+		t.Error("tools withheld although no call repeated 3 times")
+	}
+}
+
+func TestLoopGuardIgnoresEarlierTurns(t *testing.T) {
+	g := NewLoopGuard(2)
+	req := loopRequest(searchCall("1", `{"q":"price"}`), searchCall("2", `{"q":"price"}`))
+	// A new user message starts a new run; the earlier repeats don't count.
+	req.Messages = append(req.Messages, core.UserMessage("and the shipping cost?"))
+	if err := g.PreLLM(context.Background(), req); err != nil {
+		t.Fatal(err)
+	}
+	if req.Tools == nil {
+		t.Error("repeats from a previous turn should not trigger the guard")
+	}
+}
@@ -0,0 +1,184 @@
+package guardrail
+
+import (
+	"context"
+	"log/slog"
+	"sync"
+	"time"
+
+	"github.com/nevindra/oasis/core"
+)
+
+// QuotaPeriod is the calendar window over which a QuotaGuard accumulates usage.
+type QuotaPeriod int
+
+const (
+	// QuotaDaily resets at local midnight.
+	QuotaDaily QuotaPeriod = iota
+	// QuotaMonthly resets at local midnight on the first of the month.
+	QuotaMonthly
+)
+
+// QuotaGuard refuses new LLM calls once token or request usage for the
+// current calendar period crosses its limits. Unlike CostGuard (one run),
+// usage accumulates across every run that shares the guard, so it caps the
+// total spend of a long agent loop or a misbehaving scheduled job. Counters
+// reset at the period boundary. Safe for concurrent use.
+//
+// Usage is held in memory and starts at zero on process start; seed it with
+// QuotaUsed from a persisted usage log to carry a monthly budget across
+// restarts.
+type QuotaGuard struct {
+	mu          sync.Mutex
+	period      QuotaPeriod
+	loc         *time.Location
+	maxTokens   int
+	maxRequests int
+	tokens      int
+	requests    int
+	start       time.Time // start of the period the counters belong to
+	response    string
+	logger      *slog.Logger
+	now         func() time.Time
+}
+
+// QuotaOption configures a QuotaGuard.
+type QuotaOption func(*QuotaGuard)
+
+// NewQuotaGuard returns a guard that enforces per-period limits. With no
+// limits configured it only counts.
+func NewQuotaGuard(period QuotaPeriod, opts ...QuotaOption) *QuotaGuard {
+	g := &QuotaGuard{
+		period:   period,
+		loc:      time.Local,
+		response: "Usage quota reached. Try again after the quota resets.",
+		now:      time.Now,
+	}
+	for _, opt := range opts {
+		opt(g)
+	}
+	if g.logger == nil {
+		g.logger = nopLogger
+	}
+	g.start = g.periodStart(g.now())
+	return g
+}
+
+// MaxPeriodTokens caps input + output tokens per period. Zero means unlimited.
+func MaxPeriodTokens(n int) QuotaOption {
+	return func(g *QuotaGuard) { g.maxTokens = n }
+}
+
+// MaxPeriodRequests caps LLM calls per period. Zero means unlimited.
+func MaxPeriodRequests(n int) QuotaOption {
+	return func(g *QuotaGuard) { g.maxRequests = n }
+}
+
+// QuotaLocation sets the time zone that defines period boundaries
+// (default time.Local).
+func QuotaLocation(loc *time.Location) QuotaOption {
+	return func(g *QuotaGuard) {
+		if loc != nil {
+			g.loc = loc
+		}
+	}
+}
+
+// QuotaUsed seeds the current period's counters, e.g. from a persisted
+// usage log after a restart.
+func QuotaUsed(tokens, requests int) QuotaOption {
+	return func(g *QuotaGuard) {
+		g.tokens = tokens
+		g.requests = requests
+	}
+}
+
+// QuotaResponse sets the canned response returned when a call is refused.
+func QuotaResponse(msg string) QuotaOption {
+	return func(g *QuotaGuard) { g.response = msg }
+}
+
+// QuotaLogger sets the guard's logger.
+func QuotaLogger(l *slog.Logger) QuotaOption {
+	return func(g *QuotaGuard) { g.logger = l }
+}
+
+// SetLimits replaces the token and request limits at runtime (e.g. an
+// operator raising the ceiling). Usage already counted this period is kept.
+func (g *QuotaGuard) SetLimits(maxTokens, maxRequests int) {
+	g.mu.Lock()
+	defer g.mu.Unlock()
+	g.maxTokens = maxTokens
+	g.maxRequests = maxRequests
+}
+
+// Used returns the tokens and requests counted in the current period.
+func (g *QuotaGuard) Used() (tokens, requests int) {
+	g.mu.Lock()
+	defer g.mu.Unlock()
+	g.roll()
+	return g.tokens, g.requests
+}
+
+// PreLLM refuses the call if the period's quota is exhausted; otherwise it
+// counts the request.
+func (g *QuotaGuard) PreLLM(_ context.Context, _ *core.ChatRequest) error {
+	g.mu.Lock()
+	defer g.mu.Unlock()
+	g.roll()
+	if g.exceeded() {
+		g.logger.Warn("usage quota exceeded — refusing LLM call",
+			"tokens", g.tokens, "max_tokens", g.maxTokens,
+			"requests", g.requests, "max_requests", g.maxRequests,
+			"period_start", g.start)
+		return &core.ErrHalt{Response: g.response}
+	}
+	g.requests++
+	return nil
+}
+
+// PostLLM adds the response's token usage to the period total. The call that
+// crosses the limit completes; the next one is refused.
+func (g *QuotaGuard) PostLLM(_ context.Context, resp *core.ChatResponse) error {
+	if resp == nil {
+		return nil
+	}
+	g.mu.Lock()
+	defer g.mu.Unlock()
+	g.roll()
+	g.tokens += resp.Usage.InputTokens + resp.Usage.OutputTokens
+	return nil
+}
+
+// exceeded reports whether either limit is reached. Caller holds g.mu.
+func (g *QuotaGuard) exceeded() bool {
+	return (g.maxTokens > 0 && g.tokens >= g.maxTokens) ||
+		(g.maxRequests > 0 && g.requests >= g.maxRequests)
+}
+
+// roll resets the counters when the clock has entered a new period. Caller
+// holds g.mu.
+func (g *QuotaGuard) roll() {
+	start := g.periodStart(g.now())
+	if start.Equal(g.start) {
+		return
+	}
+	g.start = start
+	g.tokens = 0
+	g.requests = 0
+}
+
+// periodStart returns the start of the period containing t.
+func (g *QuotaGuard) periodStart(t time.Time) time.Time {
+	t = t.In(g.loc)
+	if g.period == QuotaMonthly {
+		return time.Date(t.Year(), t.Month(), 1, 0, 0, 0, 0, g.loc)
+	}
+	return time.Date(t.Year(), t.Month(), t.Day(), 0, 0, 0, 0, g.loc)
+}
+
+// compile-time checks
+var (
+	_ core.PreProcessor  = (*QuotaGuard)(nil)
+	_ core.PostProcessor = (*QuotaGuard)(nil)
+)
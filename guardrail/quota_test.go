@@ -0,0 +1,79 @@
+package guardrail
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/nevindra/oasis/core"
+)
+
+// fakeClock returns a QuotaGuard clock that reads *t.
+func fakeClock(t *time.Time) func() time.Time {
+	return func() time.Time { return *t }
+}
+
+func TestQuotaGuardRefusesOverTokens(t *testing.T) {
+	now := time.Date(2026, 3, 10, 9, 0, 0, 0, time.UTC)
+	g := NewQuotaGuard(QuotaDaily, MaxPeriodTokens(1000), QuotaLocation(time.UTC))
+	g.now = fakeClock(&now)
+	ctx := context.Background()
+
+	if err := g.PreLLM(ctx, &core.ChatRequest{}); err != nil {
+		t.Fatalf("first call refused: %v", err)
+	}
+	_ = g.PostLLM(ctx, &core.ChatResponse{Usage: core.Usage{InputTokens: 700, OutputTokens: 400}})
+
+	err := g.PreLLM(ctx, &core.ChatRequest{})
+	if _, ok := err.(*core.ErrHalt); !ok {
+		t.Fatalf("expected *core.ErrHalt over quota, got %v", err)
+	}
+	if tokens, requests := g.Used(); tokens != 1100 || requests != 1 {
+		t.Errorf("Used() = %d tokens, %d requests; want 1100, 1 (refused call not counted)", tokens, requests)
+	}
+}
+
+func TestQuotaGuardRequestLimit(t *testing.T) {
+	g := NewQuotaGuard(QuotaDaily, MaxPeriodRequests(2))
+	ctx := context.Background()
+	for i := 0; i < 2; i++ {
+		if err := g.PreLLM(ctx, &core.ChatRequest{}); err != nil {
+			t.Fatalf("call %d refused: %v", i, err)
+		}
+	}
+	if err := g.PreLLM(ctx, &core.ChatRequest{}); err == nil {
+		t.Error("third call should be refused")
+	}
+}
+
+func TestQuotaGuardResetsAtPeriodBoundary(t *testing.T) {
+	now := time.Date(2026, 3, 31, 23, 59, 0, 0, time.UTC)
+	g := NewQuotaGuard(QuotaMonthly, MaxPeriodTokens(100), QuotaLocation(time.UTC))
+	g.now = fakeClock(&now)
+	ctx := context.Background()
+	_ = g.PreLLM(ctx, &core.ChatRequest{})
+	_ = g.PostLLM(ctx, &core.ChatResponse{Usage: core.Usage{InputTokens: 100}})
+	if err := g.PreLLM(ctx, &core.ChatRequest{}); err == nil {
+		t.Fatal("expected refusal before the boundary")
+	}
+
+	now = time.Date(2026, 4, 1, 0, 0, 1, 0, time.UTC)
+	if err := g.PreLLM(ctx, &core.ChatRequest{}); err != nil {
+		t.Errorf("expected reset in the new month, got %v", err)
+	}
+	if tokens, _ := g.Used(); tokens != 0 {
+		t.Errorf("tokens after reset = %d, want 0", tokens)
+	}
+}
+
+func TestQuotaGuardSetLimitsRaisesCeiling(t *testing.T) {
+	g := NewQuotaGuard(QuotaDaily, MaxPeriodTokens(10), QuotaUsed(10, 1))
+	ctx := context.Background()
+	if err := g.PreLLM(ctx, &core.ChatRequest{}); err == nil {
+		t.Fatal("expected refusal with seeded usage at the limit")
+	}
+	g.SetLimits(1000, 0)
+	if err := g.PreLLM(ctx, &core.ChatRequest{}); err != nil {
+		t.Errorf("expected call allowed after raising the limit, got %v", err)
+	}
+}
@@ -62,19 +62,32 @@ func TokenBudgetLogger(l *slog.Logger) TokenBudgetOption {
 	return func(g *TokenBudgetGuard) { g.logger = l }
 }
 
-// PreLLM trims oldest non-system messages until the estimate fits.
-func (g *TokenBudgetGuard) PreLLM(_ context.Context, req *core.ChatRequest) error {
-	if g.maxTokens <= 0 || g.estimate(req.Messages) <= g.maxTokens {
+// PreLLM trims oldest non-system messages until the estimate fits. System
+// messages, the PreserveLast window, and the most recent user message are
+// never trimmed — in a tool-calling iteration the tail is a tool result, so
+// the window alone would not protect the question being answered. When only
+// protected messages remain the request is forwarded over budget.
+func (g *TokenBudgetGuard) PreLLM(ctx context.Context, req *core.ChatRequest) error {
+	before := g.estimate(req.Messages)
+	if g.maxTokens <= 0 || before <= g.maxTokens {
 		return nil
 	}
-	msgs := req.Messages
+	// Why: req.Messages aliases the run loop's history slice. Trimming in
+	// place would shift entries inside the caller's backing array and leave
+	// its history (still the original length) with duplicated tail messages.
+	msgs := make([]core.ChatMessage, len(req.Messages))
+	copy(msgs, req.Messages)
+	keep := lastUserIndex(msgs)
 	// Trim oldest non-system, non-preserved messages first.
 	for g.estimate(msgs) > g.maxTokens {
-		idx := firstTrimmable(msgs, g.preserveLast)
+		idx := firstTrimmable(msgs, g.preserveLast, keep)
 		if idx < 0 {
 			break // nothing left to trim but the protected set
 		}
 		msgs = append(msgs[:idx], msgs[idx+1:]...)
+		if idx < keep {
+			keep--
+		}
 	}
 	// Cleanup: remove any tool-result whose originating tool call is no longer
 	// present in the remaining messages. A leading-only check is insufficient
@@ -96,20 +109,28 @@ func (g *TokenBudgetGuard) PreLLM(_ context.Context, req *core.ChatRequest) erro
 		filtered = append(filtered, m)
 	}
 	msgs = filtered
+	after := g.estimate(msgs)
 	if len(msgs) != len(req.Messages) {
 		g.logger.Warn("token budget trimmed messages",
-			"from", len(req.Messages), "to", len(msgs), "max_tokens", g.maxTokens)
+			"from", len(req.Messages), "to", len(msgs),
+			"est_tokens_before", before, "est_tokens_after", after,
+			"max_tokens", g.maxTokens)
+	}
+	if after > g.maxTokens {
+		g.logger.Warn("token budget exceeded by protected messages",
+			"est_tokens", after, "max_tokens", g.maxTokens)
 	}
 	req.Messages = msgs
 	return nil
 }
 
 // firstTrimmable returns the index of the oldest message that is neither a
-// system message nor inside the preserveLast window, or -1 if none.
-func firstTrimmable(msgs []core.ChatMessage, preserveLast int) int {
+// system message, inside the preserveLast window, nor at index keep, or -1
+// if none.
+func firstTrimmable(msgs []core.ChatMessage, preserveLast, keep int) int {
 	cutoff := len(msgs) - preserveLast
 	for i := 0; i < cutoff; i++ {
-		if msgs[i].Role == "system" {
+		if msgs[i].Role == core.RoleSystem || i == keep {
 			continue
 		}
 		return i
@@ -117,6 +138,16 @@ func firstTrimmable(msgs []core.ChatMessage, preserveLast int) int {
 	return -1
 }
 
+// lastUserIndex returns the index of the most recent user message, or -1.
+func lastUserIndex(msgs []core.ChatMessage) int {
+	for i := len(msgs) - 1; i >= 0; i-- {
+		if msgs[i].Role == core.RoleUser {
+			return i
+		}
+	}
+	return -1
+}
+
 // defaultTokenEstimate mirrors the compaction heuristic: ~1 token per 3 runes,
 // padded hot, plus a fixed cost per image/PDF attachment.
 func defaultTokenEstimate(msgs []core.ChatMessage) int {
@@ -106,3 +106,64 @@ func TestTokenBudgetUnderBudgetNoOp(t *testing.T) {
 		t.Errorf("under-budget input must be untouched, got %d messages", len(req.Messages))
 	}
 }
+
+func TestTokenBudgetPreservesLatestUserMessage(t *testing.T) {
+	// Mid tool loop: the tail is a tool result, so PreserveLast(1) alone would
+	// let the user's question be trimmed.
+	g := NewTokenBudgetGuard(40)
+	question := "what changed in the report? " + strings.Repeat("q", 90)
+	req := core.ChatRequest{Messages: []core.ChatMessage{
+		{Role: "system", Content: "You are a helpful assistant."},
+		{Role: "user", Content: strings.Repeat("old", 100)},
+		{Role: "assistant", Content: strings.Repeat("ans", 100)},
+		{Role: "user", Content: question},
+		{Role: "assistant", ToolCalls: []core.ToolCall{{ID: "c1", Name: "read"}}},
+		{Role: "tool", Content: "done", ToolCallID: "c1"},
+	}}
+	if err := g.PreLLM(context.Background(), &req); err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	var sawSystem, sawQuestion bool
+	for _, m := range req.Messages {
+		if m.Role == core.RoleSystem {
+			sawSystem = true
+		}
+		if m.Content == question {
+			sawQuestion = true
+		}
+		if strings.HasPrefix(m.Content, "oldold") {
+			t.Error("oldest history should have been trimmed first")
+		}
+	}
+	if !sawSystem {
+		t.Error("system prompt must survive")
+	}
+	if !sawQuestion {
+		t.Errorf("latest user message must survive, got %+v", req.Messages)
+	}
+}
+
+func TestTokenBudgetDoesNotMutateCallerSlice(t *testing.T) {
+	// The run loop hands PreLLM its own history slice; trimming must not
+	// rewrite that backing array.
+	g := NewTokenBudgetGuard(5)
+	history := []core.ChatMessage{
+		{Role: "system", Content: "s"},
+		{Role: "user", Content: strings.Repeat("a", 100)},
+		{Role: "assistant", Content: strings.Repeat("b", 100)},
+		{Role: "user", Content: "newest"},
+	}
+	orig := append([]core.ChatMessage(nil), history...)
+	req := core.ChatRequest{Messages: history}
+	if err := g.PreLLM(context.Background(), &req); err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if len(req.Messages) >= len(history) {
+		t.Fatalf("expected trimming, got %d messages", len(req.Messages))
+	}
+	for i := range history {
+		if history[i].Content != orig[i].Content {
+			t.Errorf("caller history[%d] changed from %q to %q", i, orig[i].Content, history[i].Content)
+		}
+	}
+}
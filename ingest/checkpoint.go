@@ -198,10 +198,12 @@ func (ing *Ingestor) resumeFromCheckpoint(ctx context.Context, cp oasis.IngestCh
 	}
 
 	// --- store ---
+	var replacedID string
 	if cp.Status != oasis.CheckpointGraphing {
 		cp.Status = oasis.CheckpointStoring
 		ing.saveCheckpoint(ctx, cp)
-		if err := ing.store.StoreDocument(ctx, doc, chunks); err != nil {
+		var err error
+		if replacedID, err = ing.storeDocument(ctx, doc, chunks); err != nil {
 			err = fmt.Errorf("store: %w", err)
 			ing.notifyError(source, err)
 			return IngestResult{}, err
@@ -223,6 +225,7 @@ func (ing *Ingestor) resumeFromCheckpoint(ctx context.Context, cp oasis.IngestCh
 		DocumentID: docID,
 		Document:   doc,
 		ChunkCount: len(chunks),
+		ReplacedID: replacedID,
 	}
 	if ing.logger != nil {
 		ing.logger.Info("ingest: resume completed",
@@ -248,7 +251,8 @@ func (ing *Ingestor) extractWithRetry(ctx context.Context, extractor Extractor,
 		if err == nil {
 			return text, nil
 		}
-		if errors.Is(err, context.Canceled) || errors.Is(err, context.DeadlineExceeded) {
+		if errors.Is(err, context.Canceled) || errors.Is(err, context.DeadlineExceeded) ||
+			errors.Is(err, ErrUnsupportedContent) {
 			return "", err
 		}
 		last = err
@@ -1,10 +1,13 @@
 package ingest
 
 import (
+	"bytes"
 	"context"
+	"errors"
 	"strconv"
 	"strings"
 	"unicode"
+	"unicode/utf16"
 	"unicode/utf8"
 
 	oasis "github.com/nevindra/oasis/core"
@@ -19,6 +22,9 @@ type Extractor interface {
 type ExtractResult struct {
 	Text string
 	Meta []PageMeta
+	// OCR reports that Text came from an OCR or vision fallback rather than
+	// the document's embedded text layer (see WithOCRFallback).
+	OCR bool
 }
 
 // PageMeta holds metadata for a single page or section of extracted content.
@@ -72,13 +78,144 @@ func ContentTypeFromExtension(ext string) ContentType {
 	}
 }
 
+// File signatures recognised by DetectContentType.
+var (
+	pdfMagic   = []byte("%PDF-")
+	zipMagic   = []byte("PK\x03\x04")
+	docxMarker = []byte("word/document.xml")
+	utf8BOM    = []byte("\xEF\xBB\xBF")
+)
+
+// DetectContentType identifies content from its leading bytes, returning ""
+// when no known signature matches. PDF and DOCX are recognised by their file
+// signatures, HTML by a leading <!doctype html> or <html> tag. Plain text,
+// markdown, CSV, and JSON have no reliable signature and are never reported.
+func DetectContentType(content []byte) ContentType {
+	switch {
+	case bytes.HasPrefix(content, pdfMagic):
+		return TypePDF
+	case bytes.HasPrefix(content, zipMagic) && bytes.Contains(content, docxMarker):
+		// Other OOXML formats (xlsx, pptx) are zips too; only a Word
+		// document part makes it a DOCX.
+		return TypeDOCX
+	case looksHTML(content):
+		return TypeHTML
+	}
+	return ""
+}
+
+// looksHTML reports whether content opens with an HTML doctype or root tag,
+// ignoring a UTF-8 BOM and leading whitespace.
+func looksHTML(content []byte) bool {
+	content = bytes.TrimLeft(bytes.TrimPrefix(content, utf8BOM), " \t\r\n")
+	head := bytes.ToLower(content[:min(len(content), len("<!doctype html"))])
+	return bytes.HasPrefix(head, []byte("<!doctype html")) || bytes.HasPrefix(head, []byte("<html"))
+}
+
 // --- Built-in extractors ---
 
-// PlainTextExtractor returns content as-is.
+// ErrUnsupportedContent is returned when content is binary data with no
+// registered extractor (an archive, image, or executable uploaded under an
+// unknown extension). Callers can match it with errors.Is to tell the user
+// which formats are supported instead of reporting a generic failure.
+var ErrUnsupportedContent = errors.New("unsupported binary content (supported: txt, md, html, csv, json, docx, pdf)")
+
+// UTF-16 byte order marks recognised by PlainTextExtractor.
+var (
+	utf16LEBOM = []byte("\xFF\xFE")
+	utf16BEBOM = []byte("\xFE\xFF")
+)
+
+// binarySniffLen is how many leading bytes looksBinary inspects.
+const binarySniffLen = 8000
+
+// PlainTextExtractor returns text content as-is. UTF-16 text with a byte
+// order mark is decoded to UTF-8, and binary content is rejected with
+// ErrUnsupportedContent. Bytes that are not valid UTF-8 are decoded as
+// Windows-1252, the common legacy encoding, while valid UTF-8 around them is
+// kept, so a file is never stored with invalid bytes.
 type PlainTextExtractor struct{}
 
 func (PlainTextExtractor) Extract(_ context.Context, content []byte) (string, error) {
-	return string(content), nil
+	// Why: UTF-16 text is full of NUL bytes, so it must be decoded before
+	// the binary sniff would reject it.
+	if text, ok := decodeUTF16(content); ok {
+		return text, nil
+	}
+	if looksBinary(content) {
+		return "", ErrUnsupportedContent
+	}
+	if utf8.Valid(content) {
+		return string(content), nil
+	}
+	return decodeInvalidUTF8(content), nil
+}
+
+// decodeUTF16 decodes content that starts with a UTF-16 byte order mark,
+// dropping the mark. ok is false when there is no UTF-16 BOM.
+func decodeUTF16(content []byte) (text string, ok bool) {
+	var order func([]byte) uint16
+	switch {
+	case bytes.HasPrefix(content, utf16LEBOM):
+		order = func(b []byte) uint16 { return uint16(b[0]) | uint16(b[1])<<8 }
+	case bytes.HasPrefix(content, utf16BEBOM):
+		order = func(b []byte) uint16 { return uint16(b[0])<<8 | uint16(b[1]) }
+	default:
+		return "", false
+	}
+	content = content[2:]
+	units := make([]uint16, 0, len(content)/2)
+	for i := 0; i+1 < len(content); i += 2 {
+		units = append(units, order(content[i:]))
+	}
+	return string(utf16.Decode(units)), true
+}
+
+// looksBinary reports whether the leading bytes contain a NUL or other
+// control bytes that never appear in text. The set matches the one
+// net/http.DetectContentType uses to tell text from binary.
+func looksBinary(content []byte) bool {
+	if len(content) > binarySniffLen {
+		content = content[:binarySniffLen]
+	}
+	for _, b := range content {
+		if b <= 0x08 || b == 0x0B || (b >= 0x0E && b <= 0x1A) || (b >= 0x1C && b <= 0x1F) {
+			return true
+		}
+	}
+	return false
+}
+
+// windows1252 maps bytes 0x80-0x9F to the characters Windows-1252 assigns
+// them (curly quotes, dashes, the euro sign). Zero entries are unassigned in
+// Windows-1252 and fall back to the Latin-1 code point of the same value.
+var windows1252 = [32]rune{
+	0x20AC, 0, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021,
+	0x02C6, 0x2030, 0x0160, 0x2039, 0x0152, 0, 0x017D, 0,
+	0, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+	0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0, 0x017E, 0x0178,
+}
+
+// decodeInvalidUTF8 keeps valid UTF-8 sequences as they are and decodes each
+// invalid byte as Windows-1252, so one stray legacy byte in an otherwise
+// UTF-8 file does not mangle the rest of it.
+func decodeInvalidUTF8(content []byte) string {
+	var sb strings.Builder
+	sb.Grow(len(content) + len(content)/4)
+	for len(content) > 0 {
+		r, size := utf8.DecodeRune(content)
+		if r == utf8.RuneError && size <= 1 {
+			b := content[0]
+			r = rune(b)
+			if b >= 0x80 && b <= 0x9F && windows1252[b-0x80] != 0 {
+				r = windows1252[b-0x80]
+			}
+			size = 1
+		}
+		sb.WriteRune(r)
+		content = content[size:]
+	}
+	return sb.String()
 }
 
 // HTMLExtractor strips HTML tags, scripts, styles, and decodes entities.
@@ -5,6 +5,7 @@ import (
 	"context"
 	"fmt"
 	"strings"
+	"unicode"
 
 	"github.com/ledongthuc/pdf"
 )
@@ -13,11 +14,46 @@ import (
 var _ Extractor = (*PDFExtractor)(nil)
 var _ MetadataExtractor = (*PDFExtractor)(nil)
 
+// defaultMinCharsPerPage is the average extracted characters per page below
+// which a PDF is treated as scanned (no usable text layer).
+const defaultMinCharsPerPage = 50
+
 // PDFExtractor implements Extractor and MetadataExtractor for PDF documents.
-type PDFExtractor struct{}
+type PDFExtractor struct {
+	minCharsPerPage int
+	ocr             Extractor
+}
+
+// PDFOption configures a PDFExtractor.
+type PDFOption func(*PDFExtractor)
+
+// WithOCRFallback sets an extractor used when the PDF has little or no text
+// layer (scanned or image-only pages). The fallback receives the original PDF
+// bytes — typically an OCR sidecar or a vision-LLM-backed extractor. If it
+// also implements MetadataExtractor, ExtractWithMeta is used. Digital PDFs
+// never reach the fallback.
+func WithOCRFallback(e Extractor) PDFOption {
+	return func(x *PDFExtractor) { x.ocr = e }
+}
+
+// WithMinCharsPerPage sets the average extracted characters per page below
+// which the text layer is considered unusable (default 50).
+func WithMinCharsPerPage(n int) PDFOption {
+	return func(x *PDFExtractor) {
+		if n > 0 {
+			x.minCharsPerPage = n
+		}
+	}
+}
 
 // NewPDFExtractor creates a PDF extractor.
-func NewPDFExtractor() *PDFExtractor { return &PDFExtractor{} }
+func NewPDFExtractor(opts ...PDFOption) *PDFExtractor {
+	e := &PDFExtractor{minCharsPerPage: defaultMinCharsPerPage}
+	for _, opt := range opts {
+		opt(e)
+	}
+	return e
+}
 
 // Extract extracts plain text from a PDF document.
 func (e *PDFExtractor) Extract(ctx context.Context, content []byte) (string, error) {
@@ -28,8 +64,10 @@ func (e *PDFExtractor) Extract(ctx context.Context, content []byte) (string, err
 	return result.Text, nil
 }
 
-// ExtractWithMeta extracts text page-by-page with page number metadata.
-func (e *PDFExtractor) ExtractWithMeta(_ context.Context, content []byte) (ExtractResult, error) {
+// ExtractWithMeta extracts text page-by-page with page number metadata. When
+// the text layer is too thin (see WithMinCharsPerPage) and an OCR fallback is
+// configured, the fallback's result is returned with OCR set.
+func (e *PDFExtractor) ExtractWithMeta(ctx context.Context, content []byte) (ExtractResult, error) {
 	if len(content) == 0 {
 		return ExtractResult{}, fmt.Errorf("empty PDF content")
 	}
@@ -39,7 +77,8 @@ func (e *PDFExtractor) ExtractWithMeta(_ context.Context, content []byte) (Extra
 	}
 	var text strings.Builder
 	var meta []PageMeta
-	for i := 1; i <= r.NumPage(); i++ {
+	numPages := r.NumPage()
+	for i := 1; i <= numPages; i++ {
 		page := r.Page(i)
 		if page.V.IsNull() {
 			continue
@@ -64,10 +103,48 @@ func (e *PDFExtractor) ExtractWithMeta(_ context.Context, content []byte) (Extra
 			EndByte:    endByte,
 		})
 	}
-	return ExtractResult{
+	result := ExtractResult{
 		Text: strings.TrimSpace(text.String()),
 		Meta: meta,
-	}, nil
+	}
+	// Why: scanned PDFs parse cleanly but yield little or no text, so without
+	// this check they are indexed as empty documents and silently never match.
+	if e.ocr != nil && lowTextLayer(result.Text, numPages, e.minCharsPerPage) {
+		return e.extractOCR(ctx, content)
+	}
+	return result, nil
+}
+
+// extractOCR runs the OCR fallback and marks the result as OCR-derived.
+func (e *PDFExtractor) extractOCR(ctx context.Context, content []byte) (ExtractResult, error) {
+	if me, ok := e.ocr.(MetadataExtractor); ok {
+		result, err := me.ExtractWithMeta(ctx, content)
+		if err != nil {
+			return ExtractResult{}, fmt.Errorf("ocr fallback: %w", err)
+		}
+		result.OCR = true
+		return result, nil
+	}
+	text, err := e.ocr.Extract(ctx, content)
+	if err != nil {
+		return ExtractResult{}, fmt.Errorf("ocr fallback: %w", err)
+	}
+	return ExtractResult{Text: strings.TrimSpace(text), OCR: true}, nil
+}
+
+// lowTextLayer reports whether extracted text averages fewer than
+// minCharsPerPage non-whitespace runes per page.
+func lowTextLayer(text string, numPages, minCharsPerPage int) bool {
+	if numPages <= 0 {
+		return false
+	}
+	chars := 0
+	for _, r := range text {
+		if !unicode.IsSpace(r) {
+			chars++
+		}
+	}
+	return chars < numPages*minCharsPerPage
 }
 
 func pdfExtractPageText(page pdf.Page) (string, error) {
@@ -2,6 +2,7 @@ package ingest
 
 import (
 	"context"
+	"strings"
 	"testing"
 )
 
@@ -20,3 +21,41 @@ func TestPDFExtractWithMetaEmptyContent(t *testing.T) {
 		t.Error("expected error for empty content")
 	}
 }
+
+func TestLowTextLayer(t *testing.T) {
+	digital := strings.Repeat("Quarterly revenue grew across all regions. ", 10)
+	tests := []struct {
+		name  string
+		text  string
+		pages int
+		want  bool
+	}{
+		{"empty scan", "", 3, true},
+		{"page numbers only", "1\n\n2\n\n3", 3, true},
+		{"whitespace ignored", strings.Repeat(" \n\t", 200), 1, true},
+		{"digital page", digital, 1, false},
+		{"thin over many pages", digital, 20, true},
+		{"no pages", "", 0, false},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := lowTextLayer(tt.text, tt.pages, defaultMinCharsPerPage); got != tt.want {
+				t.Errorf("lowTextLayer(%d runes, %d pages) = %v, want %v", len(tt.text), tt.pages, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestPDFExtractOCRMarksResult(t *testing.T) {
+	e := NewPDFExtractor(WithOCRFallback(PlainTextExtractor{}))
+	result, err := e.extractOCR(context.Background(), []byte("  scanned invoice text  "))
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if !result.OCR {
+		t.Error("OCR = false, want true")
+	}
+	if result.Text != "scanned invoice text" {
+		t.Errorf("Text = %q", result.Text)
+	}
+}
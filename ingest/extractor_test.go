@@ -2,6 +2,7 @@ package ingest
 
 import (
 	"context"
+	"errors"
 	"strings"
 	"testing"
 )
@@ -17,6 +18,69 @@ func TestPlainTextExtractorIdentity(t *testing.T) {
 	}
 }
 
+func TestPlainTextExtractorRejectsBinary(t *testing.T) {
+	zip := []byte("PK\x03\x04\x14\x00\x00\x00\x08\x00payload")
+	_, err := PlainTextExtractor{}.Extract(context.Background(), zip)
+	if !errors.Is(err, ErrUnsupportedContent) {
+		t.Fatalf("err = %v, want ErrUnsupportedContent", err)
+	}
+}
+
+func TestPlainTextExtractorDecodesLatin1(t *testing.T) {
+	latin1 := []byte("caf\xe9 cr\xe8me br\xfbl\xe9e\r\n")
+	out, err := PlainTextExtractor{}.Extract(context.Background(), latin1)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if out != "café crème brûlée\r\n" {
+		t.Errorf("got %q", out)
+	}
+}
+
+func TestPlainTextExtractorKeepsValidUTF8AroundInvalidBytes(t *testing.T) {
+	// A UTF-8 file with one stray Windows-1252 smart-quote pair.
+	mixed := []byte("caf\xc3\xa9 \x93quoted\x94 cr\xc3\xa8me")
+	out, err := PlainTextExtractor{}.Extract(context.Background(), mixed)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if out != "café \u201cquoted\u201d crème" {
+		t.Errorf("got %q", out)
+	}
+}
+
+func TestPlainTextExtractorDecodesUTF16(t *testing.T) {
+	le := []byte("\xff\xfec\x00a\x00f\x00\xe9\x00\n\x00")
+	out, err := PlainTextExtractor{}.Extract(context.Background(), le)
+	if err != nil {
+		t.Fatalf("UTF-16LE: %v", err)
+	}
+	if out != "café\n" {
+		t.Errorf("UTF-16LE: got %q", out)
+	}
+
+	be := []byte("\xfe\xff\x00h\x00i\x20\x19")
+	out, err = PlainTextExtractor{}.Extract(context.Background(), be)
+	if err != nil {
+		t.Fatalf("UTF-16BE: %v", err)
+	}
+	if out != "hi\u2019" {
+		t.Errorf("UTF-16BE: got %q", out)
+	}
+}
+
+func TestIngestFileUnknownBinaryType(t *testing.T) {
+	store := &mockStore{}
+	ing := NewIngestor(store, &mockEmbedding{})
+	_, err := ing.IngestFile(context.Background(), []byte("\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR"), "photo.png")
+	if !errors.Is(err, ErrUnsupportedContent) {
+		t.Fatalf("err = %v, want ErrUnsupportedContent", err)
+	}
+	if len(store.documents) != 0 {
+		t.Error("binary upload should not be stored")
+	}
+}
+
 func TestStripHTMLBasic(t *testing.T) {
 	out := StripHTML("<p>Hello <b>world</b></p>")
 	if !strings.Contains(out, "Hello") || !strings.Contains(out, "world") {
@@ -118,6 +182,56 @@ func TestContentTypeFromExtensionNew(t *testing.T) {
 	}
 }
 
+func TestDetectContentType(t *testing.T) {
+	tests := []struct {
+		name    string
+		content []byte
+		want    ContentType
+	}{
+		{"pdf", []byte("%PDF-1.7\n%\xe2\xe3\xcf\xd3\n1 0 obj"), TypePDF},
+		{"docx", buildTestDocx(t, []testParagraph{{text: "Hi"}}), TypeDOCX},
+		{"zip without word part", []byte("PK\x03\x04\x14\x00xl/workbook.xml"), ""},
+		{"html doctype", []byte("\xEF\xBB\xBF\n  <!DOCTYPE html><html><body>x</body></html>"), TypeHTML},
+		{"html root", []byte("<html lang=\"en\">"), TypeHTML},
+		{"xml is not html", []byte("<?xml version=\"1.0\"?><feed/>"), ""},
+		{"plain text", []byte("just some notes about %PDF- files"), ""},
+		{"empty", nil, ""},
+	}
+	for _, tt := range tests {
+		if got := DetectContentType(tt.content); got != tt.want {
+			t.Errorf("%s: DetectContentType = %q, want %q", tt.name, got, tt.want)
+		}
+	}
+}
+
+func TestIngestFileDetectsTypeFromContent(t *testing.T) {
+	tests := []struct {
+		name     string
+		content  []byte
+		filename string
+		want     string
+		notWant  string
+	}{
+		{"docx without extension", buildTestDocx(t, []testParagraph{{text: "Quarterly report"}}), "file", "Quarterly report", "PK"},
+		{"html named txt", []byte("<!doctype html><html><body><p>Hello <b>world</b></p></body></html>"), "page.txt", "world", "<"},
+		{"markdown keeps its type", []byte("<html> is a tag\n\n**bold**"), "notes.md", "bold", "**"},
+	}
+	for _, tt := range tests {
+		store := &mockStore{}
+		ing := NewIngestor(store, &mockEmbedding{})
+		if _, err := ing.IngestFile(context.Background(), tt.content, tt.filename); err != nil {
+			t.Fatalf("%s: %v", tt.name, err)
+		}
+		if len(store.chunks) == 0 {
+			t.Fatalf("%s: no chunks stored", tt.name)
+		}
+		got := store.chunks[0].Content
+		if !strings.Contains(got, tt.want) || strings.Contains(got, tt.notWant) {
+			t.Errorf("%s: chunk = %q, want %q without %q", tt.name, got, tt.want, tt.notWant)
+		}
+	}
+}
+
 func TestHTMLExtractor(t *testing.T) {
 	e := HTMLExtractor{}
 	out, err := e.Extract(context.Background(), []byte("<p>Hello <b>world</b></p>"))
@@ -18,6 +18,12 @@ type IngestResult struct {
 	DocumentID string
 	Document   oasis.Document
 	ChunkCount int
+	// OCR is true when the text came from an OCR fallback because the
+	// document had no usable text layer (see WithOCRFallback).
+	OCR bool
+	// ReplacedID is the ID of the earlier document with the same Source that
+	// this ingest replaced (see WithReplaceBySource). Empty for a new source.
+	ReplacedID string
 }
 
 // defaultMaxContentSize is the default maximum content size for extraction (50 MB).
@@ -34,6 +40,8 @@ type Ingestor struct {
 	batchSize      int
 	maxContentSize int
 
+	replaceBySource bool // replace the prior document with the same Source
+
 	// cached auto-select chunkers (avoid allocation per call)
 	mdChunker       *MarkdownChunker
 	mdParentChunker *MarkdownChunker
@@ -53,6 +61,9 @@ type Ingestor struct {
 	sequenceEdges        bool
 	semanticBatching     bool
 
+	// embedWorkers is the max concurrent Embed calls (batchEmbed).
+	embedWorkers int
+
 	// contextual enrichment config
 	contextProvider    oasis.Provider
 	contextWorkers     int
@@ -96,6 +107,7 @@ func NewIngestor(store oasis.Store, emb oasis.EmbeddingProvider, opts ...Option)
 		},
 		strategy:           StrategyFlat,
 		batchSize:          64,
+		embedWorkers:       1,
 		maxContentSize:     defaultMaxContentSize,
 		mdChunker:          NewMarkdownChunker(),
 		mdParentChunker:    NewMarkdownChunker(WithMaxTokens(1024)),
@@ -189,7 +201,8 @@ func (ing *Ingestor) ingestText(ctx context.Context, text, source, title string)
 			"doc_id", docID, "chunk_count", len(chunks))
 	}
 
-	if err := ing.store.StoreDocument(ctx, doc, chunks); err != nil {
+	replacedID, err := ing.storeDocument(ctx, doc, chunks)
+	if err != nil {
 		err = fmt.Errorf("store: %w", err)
 		if ing.logger != nil {
 			ing.logger.Error("store document failed",
@@ -223,6 +236,7 @@ func (ing *Ingestor) ingestText(ctx context.Context, text, source, title string)
 		DocumentID: docID,
 		Document:   doc,
 		ChunkCount: len(chunks),
+		ReplacedID: replacedID,
 	}
 	if ing.logger != nil {
 		ing.logger.Info("ingest completed",
@@ -234,10 +248,11 @@ func (ing *Ingestor) ingestText(ctx context.Context, text, source, title string)
 	return result, nil
 }
 
-// IngestFile ingests file content, detecting the content type from the filename extension.
+// IngestFile ingests file content. The content type comes from the filename
+// extension unless the bytes carry a different known file signature (see
+// DetectContentType), in which case the signature wins.
 func (ing *Ingestor) IngestFile(ctx context.Context, content []byte, filename string) (IngestResult, error) {
-	ext := strings.TrimPrefix(filepath.Ext(filename), ".")
-	ct := ContentTypeFromExtension(ext)
+	ct := ing.resolveContentType(content, filename)
 
 	if ing.tracer != nil {
 		var span oasis.Span
@@ -261,6 +276,30 @@ func (ing *Ingestor) IngestFile(ctx context.Context, content []byte, filename st
 	return ing.ingestFile(ctx, content, filename, ct)
 }
 
+// resolveContentType picks the content type for a file: its extension,
+// overridden by DetectContentType when the bytes carry a different known
+// signature.
+func (ing *Ingestor) resolveContentType(content []byte, filename string) ContentType {
+	ext := strings.TrimPrefix(filepath.Ext(filename), ".")
+	ct := ContentTypeFromExtension(ext)
+	sniffed := DetectContentType(content)
+	if sniffed == "" || sniffed == ct {
+		return ct
+	}
+	// Why: uploads often arrive with a generic name ("file") or the wrong
+	// extension, and a file signature is more reliable than either. HTML
+	// only overrides the plain-text fallback, so a markdown or JSON file that
+	// happens to embed markup keeps its declared type.
+	if sniffed == TypeHTML && ct != TypePlainText {
+		return ct
+	}
+	if ing.logger != nil {
+		ing.logger.Debug("content type detected from file signature",
+			"source", filename, "extension_type", string(ct), "detected_type", string(sniffed))
+	}
+	return sniffed
+}
+
 func (ing *Ingestor) ingestFile(ctx context.Context, content []byte, filename string, ct ContentType) (IngestResult, error) {
 	if ing.maxContentSize > 0 && len(content) > ing.maxContentSize {
 		err := fmt.Errorf("content size %d exceeds limit %d", len(content), ing.maxContentSize)
@@ -307,6 +346,7 @@ func (ing *Ingestor) ingestFile(ctx context.Context, content []byte, filename st
 
 	var text string
 	var pageMeta []PageMeta
+	var ocr bool
 
 	// Use MetadataExtractor if available.
 	if me, ok := extractor.(MetadataExtractor); ok {
@@ -326,10 +366,15 @@ func (ing *Ingestor) ingestFile(ctx context.Context, content []byte, filename st
 		}
 		text = result.Text
 		pageMeta = result.Meta
+		ocr = result.OCR
 		if ing.logger != nil {
 			ing.logger.Debug("extraction completed",
 				"doc_id", docID, "text_bytes", len(text),
 				"page_meta_count", len(pageMeta))
+			if result.OCR {
+				ing.logger.Info("ingest: text layer too thin, used OCR fallback",
+					"doc_id", docID, "source", filename, "text_bytes", len(text))
+			}
 		}
 	} else {
 		if ing.logger != nil {
@@ -402,7 +447,8 @@ func (ing *Ingestor) ingestFile(ctx context.Context, content []byte, filename st
 			"doc_id", docID, "chunk_count", len(chunks))
 	}
 
-	if err := ing.store.StoreDocument(ctx, doc, chunks); err != nil {
+	replacedID, err := ing.storeDocument(ctx, doc, chunks)
+	if err != nil {
 		err = fmt.Errorf("store: %w", err)
 		if ing.logger != nil {
 			ing.logger.Error("store document failed",
@@ -436,6 +482,8 @@ func (ing *Ingestor) ingestFile(ctx context.Context, content []byte, filename st
 		DocumentID: docID,
 		Document:   doc,
 		ChunkCount: len(chunks),
+		OCR:        ocr,
+		ReplacedID: replacedID,
 	}
 	if ing.logger != nil {
 		ing.logger.Info("ingest completed",
@@ -589,6 +637,44 @@ func (ing *Ingestor) extractAndStoreEdges(ctx context.Context, chunks []oasis.Ch
 	return nil
 }
 
+// storeDocument persists doc and its chunks. With WithReplaceBySource it
+// returns the ID of the prior document with the same Source, which is deleted
+// only after the new one is stored.
+func (ing *Ingestor) storeDocument(ctx context.Context, doc oasis.Document, chunks []oasis.Chunk) (string, error) {
+	var prior *oasis.Document
+	finder, ok := ing.store.(oasis.DocumentSourceFinder)
+	if ing.replaceBySource && ok && doc.Source != "" {
+		var err error
+		prior, err = finder.FindDocumentBySource(ctx, doc.Source)
+		if err != nil {
+			return "", fmt.Errorf("find document by source: %w", err)
+		}
+	}
+
+	if err := ing.store.StoreDocument(ctx, doc, chunks); err != nil {
+		return "", err
+	}
+	if prior == nil || prior.ID == doc.ID {
+		return "", nil
+	}
+
+	// Why: a failed delete leaves both versions searchable, which is the same
+	// state as without WithReplaceBySource — log it rather than fail an ingest
+	// whose new content is already stored.
+	if err := ing.store.DeleteDocument(ctx, prior.ID); err != nil {
+		if ing.logger != nil {
+			ing.logger.Warn("ingest: failed to delete replaced document",
+				"doc_id", doc.ID, "replaced_id", prior.ID, "source", doc.Source, "err", err)
+		}
+		return "", nil
+	}
+	if ing.logger != nil {
+		ing.logger.Info("ingest: replaced document with same source",
+			"doc_id", doc.ID, "replaced_id", prior.ID, "source", doc.Source)
+	}
+	return prior.ID, nil
+}
+
 // notifyError fires the onError hook if set.
 func (ing *Ingestor) notifyError(source string, err error) {
 	if ing.onError != nil {
@@ -901,64 +987,107 @@ func (ing *Ingestor) selectChunker(ct ContentType) Chunker {
 	return ing.chunker
 }
 
-// batchEmbed embeds chunks in batches of ing.batchSize.
-// onBatchDone, when non-nil, is called after each successful batch with the
-// cumulative number of completed batches. This allows callers to save
-// checkpoint progress so that a partial failure doesn't discard successful
-// embedding work.
+// batchEmbed embeds chunks in batches of ing.batchSize, running up to
+// ing.embedWorkers Embed calls at once. Each batch's vectors land on the
+// chunks they were computed for, whatever order the calls finish in.
+// onBatchDone, when non-nil, is called with the cumulative number of
+// completed batches each time the finished prefix grows. This allows callers
+// to save checkpoint progress so that a partial failure doesn't discard
+// successful embedding work; a batch that finished ahead of an unfinished one
+// is re-embedded on resume.
 func (ing *Ingestor) batchEmbed(ctx context.Context, chunks []oasis.Chunk, onBatchDone func(completedBatches int)) error {
 	if len(chunks) == 0 {
 		return nil
 	}
 
 	totalBatches := (len(chunks) + ing.batchSize - 1) / ing.batchSize
+	numWorkers := min(max(ing.embedWorkers, 1), totalBatches)
 	if ing.logger != nil {
 		ing.logger.Info("embedding started",
 			"chunk_count", len(chunks),
 			"batch_size", ing.batchSize,
-			"total_batches", totalBatches)
+			"total_batches", totalBatches,
+			"workers", numWorkers)
+	}
+
+	// Texts are copied up front so workers never read chunks while this
+	// goroutine writes embeddings into them.
+	texts := make([]string, len(chunks))
+	for i, c := range chunks {
+		texts[i] = c.Content
+	}
+
+	type batchResult struct {
+		index      int // 0-based batch index
+		embeddings [][]float32
+		err        error
+	}
+	// Why: cancelled on return so that after a failed batch the remaining
+	// workers stop issuing Embed calls. results is buffered for every batch,
+	// so they never block on a reader that has gone away.
+	ctx, cancel := context.WithCancel(ctx)
+	defer cancel()
+	work := make(chan int, totalBatches)
+	for b := range totalBatches {
+		work <- b
+	}
+	close(work)
+	results := make(chan batchResult, totalBatches)
+
+	for w := 0; w < numWorkers; w++ {
+		go func() {
+			for b := range work {
+				if err := ctx.Err(); err != nil {
+					results <- batchResult{index: b, err: err}
+					continue
+				}
+				i := b * ing.batchSize
+				end := min(i+ing.batchSize, len(chunks))
+				if ing.logger != nil {
+					ing.logger.Debug("embedding batch",
+						"batch", b+1, "total_batches", totalBatches,
+						"chunks_in_batch", end-i)
+				}
+				embeddings, err := ing.embedding.Embed(ctx, texts[i:end])
+				if err == nil && ing.logger != nil && len(embeddings) > 0 {
+					ing.logger.Debug("embedding batch completed",
+						"batch", b+1, "embeddings_returned", len(embeddings),
+						"dimensions", len(embeddings[0]))
+				}
+				results <- batchResult{index: b, embeddings: embeddings, err: err}
+			}
+		}()
 	}
 
-	for i := 0; i < len(chunks); i += ing.batchSize {
-		end := min(i+ing.batchSize, len(chunks))
-		batchNum := i/ing.batchSize + 1
-
-		batch := chunks[i:end]
-		texts := make([]string, len(batch))
-		for j, c := range batch {
-			texts[j] = c.Content
-		}
-
-		if ing.logger != nil {
-			ing.logger.Debug("embedding batch",
-				"batch", batchNum, "total_batches", totalBatches,
-				"chunks_in_batch", len(batch))
-		}
-
-		embeddings, err := ing.embedding.Embed(ctx, texts)
-		if err != nil {
+	finished := make([][][]float32, totalBatches)
+	ready := make([]bool, totalBatches)
+	next := 0 // first batch not yet applied to chunks
+	for range totalBatches {
+		r := <-results
+		if r.err != nil {
+			i := r.index * ing.batchSize
+			end := min(i+ing.batchSize, len(chunks))
 			if ing.logger != nil {
 				ing.logger.Error("embedding batch failed",
-					"batch", batchNum, "range", fmt.Sprintf("%d-%d", i, end),
-					"err", err)
+					"batch", r.index+1, "range", fmt.Sprintf("%d-%d", i, end),
+					"err", r.err)
 			}
-			return fmt.Errorf("embed batch %d-%d: %w", i, end, err)
+			return fmt.Errorf("embed batch %d-%d: %w", i, end, r.err)
 		}
-
-		if ing.logger != nil && len(embeddings) > 0 {
-			ing.logger.Debug("embedding batch completed",
-				"batch", batchNum, "embeddings_returned", len(embeddings),
-				"dimensions", len(embeddings[0]))
-		}
-
-		for j := range batch {
-			if j < len(embeddings) {
-				chunks[i+j].Embedding = embeddings[j]
+		finished[r.index], ready[r.index] = r.embeddings, true
+		for next < totalBatches && ready[next] {
+			i := next * ing.batchSize
+			end := min(i+ing.batchSize, len(chunks))
+			for j := range end - i {
+				if j < len(finished[next]) {
+					chunks[i+j].Embedding = finished[next][j]
+				}
+			}
+			finished[next] = nil
+			next++
+			if onBatchDone != nil {
+				onBatchDone(next)
 			}
-		}
-
-		if onBatchDone != nil {
-			onBatchDone(batchNum)
 		}
 	}
 
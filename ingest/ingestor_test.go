@@ -5,7 +5,9 @@ import (
 	"fmt"
 	"io"
 	"strings"
+	"sync/atomic"
 	"testing"
+	"time"
 
 	oasis "github.com/nevindra/oasis/core"
 )
@@ -171,6 +173,69 @@ func TestIngestorBatchEmbedding(t *testing.T) {
 	}
 }
 
+// barrierEmbedding blocks each Embed call until `want` calls are in flight
+// (or a timeout passes), and embeds each text as its numeric suffix.
+type barrierEmbedding struct {
+	want     int32
+	inFlight atomic.Int32
+	maxSeen  atomic.Int32
+	released atomic.Bool
+	arrived  chan struct{}
+}
+
+func (b *barrierEmbedding) Embed(_ context.Context, texts []string) ([][]float32, error) {
+	n := b.inFlight.Add(1)
+	defer b.inFlight.Add(-1)
+	for {
+		m := b.maxSeen.Load()
+		if n <= m || b.maxSeen.CompareAndSwap(m, n) {
+			break
+		}
+	}
+	if n == b.want && b.released.CompareAndSwap(false, true) {
+		close(b.arrived)
+	}
+	select {
+	case <-b.arrived:
+	case <-time.After(time.Second):
+	}
+	out := make([][]float32, len(texts))
+	for i, text := range texts {
+		var idx int
+		fmt.Sscanf(text, "chunk %d", &idx)
+		out[i] = []float32{float32(idx)}
+	}
+	return out, nil
+}
+func (b *barrierEmbedding) Dimensions() int { return 1 }
+func (b *barrierEmbedding) Name() string    { return "barrier" }
+
+func TestIngestorEmbeddingWorkers(t *testing.T) {
+	emb := &barrierEmbedding{want: 3, arrived: make(chan struct{})}
+	ing := NewIngestor(&mockStore{}, emb, WithBatchSize(2), WithEmbeddingWorkers(3))
+
+	chunks := make([]oasis.Chunk, 11)
+	for i := range chunks {
+		chunks[i].Content = fmt.Sprintf("chunk %d", i)
+	}
+	var progress []int
+	if err := ing.batchEmbed(context.Background(), chunks, func(n int) { progress = append(progress, n) }); err != nil {
+		t.Fatal(err)
+	}
+
+	if got := emb.maxSeen.Load(); got != 3 {
+		t.Errorf("max concurrent Embed calls = %d, want 3", got)
+	}
+	for i, c := range chunks {
+		if len(c.Embedding) != 1 || c.Embedding[0] != float32(i) {
+			t.Errorf("chunk %d embedding = %v, want [%d]", i, c.Embedding, i)
+		}
+	}
+	if want := []int{1, 2, 3, 4, 5, 6}; fmt.Sprint(progress) != fmt.Sprint(want) {
+		t.Errorf("progress = %v, want %v", progress, want)
+	}
+}
+
 func TestIngestorParentChildStrategy(t *testing.T) {
 	store := &mockStore{}
 	emb := &mockEmbedding{}
@@ -457,3 +522,63 @@ func TestIngestorNoHooksNilSafe(t *testing.T) {
 		t.Fatal(err)
 	}
 }
+
+// sourceFinderStore adds DocumentSourceFinder and real deletes to mockStore.
+type sourceFinderStore struct {
+	*mockStore
+}
+
+func (s sourceFinderStore) FindDocumentBySource(_ context.Context, source string) (*oasis.Document, error) {
+	for i := len(s.documents) - 1; i >= 0; i-- {
+		if s.documents[i].Source == source {
+			d := s.documents[i]
+			return &d, nil
+		}
+	}
+	return nil, nil
+}
+
+func (s sourceFinderStore) DeleteDocument(_ context.Context, id string) error {
+	for i, d := range s.documents {
+		if d.ID == id {
+			s.documents = append(s.documents[:i], s.documents[i+1:]...)
+			break
+		}
+	}
+	return nil
+}
+
+func TestIngestorReplaceBySource(t *testing.T) {
+	store := sourceFinderStore{&mockStore{}}
+	ing := NewIngestor(store, &mockEmbedding{}, WithReplaceBySource(true))
+	ctx := context.Background()
+
+	first, err := ing.IngestText(ctx, "version one", "notes.md", "Notes")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if first.ReplacedID != "" {
+		t.Errorf("first ingest ReplacedID = %q, want empty", first.ReplacedID)
+	}
+
+	second, err := ing.IngestText(ctx, "version two", "notes.md", "Notes")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if second.ReplacedID != first.DocumentID {
+		t.Errorf("ReplacedID = %q, want %q", second.ReplacedID, first.DocumentID)
+	}
+	if len(store.documents) != 1 || store.documents[0].Content != "version two" {
+		t.Errorf("documents = %+v, want only the second version", store.documents)
+	}
+
+	// Without the option the same store keeps both.
+	plain := NewIngestor(store, &mockEmbedding{})
+	r, err := plain.IngestText(ctx, "version three", "notes.md", "Notes")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if r.ReplacedID != "" || len(store.documents) != 2 {
+		t.Errorf("without option: ReplacedID = %q, %d documents; want empty, 2", r.ReplacedID, len(store.documents))
+	}
+}
@@ -53,6 +53,18 @@ func WithBatchSize(n int) Option {
 	return func(ing *Ingestor) { ing.batchSize = n }
 }
 
+// WithEmbeddingWorkers sets the max concurrent Embed calls while embedding a
+// document's chunk batches (default 1, sequential). Chunks keep their order
+// and vectors regardless of which call finishes first. Raise it for large
+// documents when the embedding provider's rate limit allows.
+//
+// It works with any EmbeddingProvider. A provider that splits each call
+// itself (openaicompat.WithEmbeddingConcurrency) multiplies with it: up to
+// workers × concurrency requests can be in flight.
+func WithEmbeddingWorkers(n int) Option {
+	return func(ing *Ingestor) { ing.embedWorkers = n }
+}
+
 // WithMaxContentSize sets the maximum allowed content size in bytes for extraction
 // (default 50 MB). Set to 0 to disable the limit.
 func WithMaxContentSize(n int) Option {
@@ -159,6 +171,17 @@ func WithBlobStore(bs oasis.BlobStore) Option {
 	return func(ing *Ingestor) { ing.blobStore = bs }
 }
 
+// WithReplaceBySource makes re-ingesting a source replace the document
+// previously stored under the same Source (filename for IngestFile, source
+// for IngestText) instead of adding a duplicate (default false). The new
+// document is stored first and the old one deleted afterwards, so a failed
+// or retried ingest never leaves the source missing. Requires a Store that
+// implements oasis.DocumentSourceFinder; otherwise it is a no-op. Sources
+// are compared exactly, and an empty source is never replaced.
+func WithReplaceBySource(b bool) Option {
+	return func(ing *Ingestor) { ing.replaceBySource = b }
+}
+
 // WithOnSuccess registers a callback invoked after each successful ingestion.
 // The callback receives the full IngestResult.
 func WithOnSuccess(fn func(IngestResult)) Option {
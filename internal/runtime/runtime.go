@@ -115,6 +115,10 @@ func Init(c *Runtime, name, description string, provider core.Provider, cfg *Con
 			Logger:    cfg.Logger,
 		}
 	}
+	// Why: memory embeds with the run's context, so counting here lets the
+	// run summary report embedding calls without touching memory itself.
+	memCfg.Embedding = countEmbedCalls(memCfg.Embedding)
+	memCfg.TrimmingEmbedding = countEmbedCalls(memCfg.TrimmingEmbedding)
 	c.mem.Init(memCfg)
 
 	// Wire the async scorer pool when scorers are attached. The pool persists to
@@ -173,6 +177,29 @@ func Init(c *Runtime, name, description string, provider core.Provider, cfg *Con
 	c.cachedLookupTool = c.tools.Lookup
 }
 
+// countEmbedCalls wraps p so each Embed call is counted against the run
+// (core.AddRunEmbedCall). Returns nil for a nil p.
+func countEmbedCalls(p core.EmbeddingProvider) core.EmbeddingProvider {
+	if p == nil {
+		return nil
+	}
+	if _, ok := p.(*countingEmbedding); ok {
+		return p
+	}
+	return &countingEmbedding{p}
+}
+
+// countingEmbedding is the EmbeddingProvider returned by countEmbedCalls.
+type countingEmbedding struct {
+	core.EmbeddingProvider
+}
+
+// Embed counts the call, then delegates.
+func (e *countingEmbedding) Embed(ctx context.Context, texts []string) ([][]float32, error) {
+	core.AddRunEmbedCall(ctx)
+	return e.EmbeddingProvider.Embed(ctx, texts)
+}
+
 // Name returns the agent's name.
 func (c *Runtime) Name() string { return c.name }
 
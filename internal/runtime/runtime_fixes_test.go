@@ -135,3 +135,35 @@ func TestEffectiveToolMiddleware_NoSliceAliasing(t *testing.T) {
 		}
 	}
 }
+
+type nopEmbedding struct{}
+
+func (nopEmbedding) Embed(_ context.Context, texts []string) ([][]float32, error) {
+	return make([][]float32, len(texts)), nil
+}
+func (nopEmbedding) Dimensions() int { return 1 }
+func (nopEmbedding) Name() string    { return "nop" }
+
+// TestCountEmbedCalls verifies the memory embedding wrapper counts each Embed
+// call against the run and is applied only once.
+func TestCountEmbedCalls(t *testing.T) {
+	if countEmbedCalls(nil) != nil {
+		t.Fatal("nil provider must stay nil")
+	}
+	p := countEmbedCalls(nopEmbedding{})
+	if countEmbedCalls(p) != p {
+		t.Error("an already counted provider was wrapped again")
+	}
+	ctx := core.WithRunUsage(context.Background())
+	for range 3 {
+		if _, err := p.Embed(ctx, []string{"x"}); err != nil {
+			t.Fatal(err)
+		}
+	}
+	if n := core.RunEmbedCalls(ctx); n != 3 {
+		t.Errorf("RunEmbedCalls = %d, want 3", n)
+	}
+	if p.Name() != "nop" || p.Dimensions() != 1 {
+		t.Error("Name/Dimensions must pass through")
+	}
+}
@@ -32,7 +32,8 @@ type IngestContext struct {
 	Candidates []core.MemoryItem
 
 	// Output flags set by processors.
-	ThreadCreated bool // set by EnsureThread when a new row was created
+	ThreadCreated bool           // set by EnsureThread when a new row was created
+	Messages      []core.Message // set by PersistMessages: the user and assistant rows written
 
 	// Wiring
 	Store     core.Store           // conversation store (threads, messages)
@@ -6,13 +6,20 @@ import (
 	"encoding/json"
 	"fmt"
 	"math/rand/v2"
+	"slices"
 	"strings"
+	"sync"
 
 	"github.com/nevindra/oasis/core"
 )
 
 const (
 	maxPersistContentLen = 50_000
+
+	// threadPruneBatch bounds how many surplus threads one ThreadRetention
+	// pass lists and deletes, so enabling retention on a chat with a long
+	// backlog drains it over several new threads instead of in one turn.
+	threadPruneBatch = 100
 )
 
 // EnsureThread creates the thread row if missing and bumps updated_at.
@@ -44,6 +51,44 @@ func (EnsureThread) Process(ctx context.Context, in *IngestContext) error {
 	return nil
 }
 
+// ThreadRetention keeps at most Max threads per chat. When a turn creates a
+// thread, the chat's least recently updated threads beyond Max are deleted
+// together with their messages. Turns on existing threads do nothing.
+// Max <= 0 disables it; turns without a ChatID are skipped.
+type ThreadRetention struct {
+	Max int
+}
+
+func (r ThreadRetention) Process(ctx context.Context, in *IngestContext) error {
+	if r.Max <= 0 || !in.ThreadCreated || in.Store == nil || in.Task.ChatID == "" {
+		return nil
+	}
+	threads, err := in.Store.ListThreads(ctx, in.Task.ChatID, r.Max+threadPruneBatch)
+	if err != nil {
+		in.Logger.Warn("list threads for retention failed", "chat_id", in.Task.ChatID, "error", err)
+		return nil
+	}
+	// Why: the new thread is counted up front rather than by position —
+	// updated_at is second-granular, so it can tie with (and sort after)
+	// older threads and must never be the one pruned.
+	kept := 1
+	for _, t := range threads {
+		if t.ID == in.Task.ThreadID {
+			continue
+		}
+		if kept < r.Max {
+			kept++
+			continue
+		}
+		if err := in.Store.DeleteThread(ctx, t.ID); err != nil {
+			in.Logger.Error("prune thread failed", "thread_id", t.ID, "error", err)
+			continue
+		}
+		in.Logger.Debug("pruned thread", "chat_id", in.Task.ChatID, "thread_id", t.ID)
+	}
+	return nil
+}
+
 // PersistMessages writes the user and assistant messages to core.Store.
 type PersistMessages struct{}
 
@@ -90,6 +135,48 @@ func (PersistMessages) Process(ctx context.Context, in *IngestContext) error {
 	if err := in.Store.StoreMessage(ctx, asst); err != nil {
 		in.Logger.Error("persist assistant message failed", "error", err)
 	}
+	in.Messages = []core.Message{user, asst}
+	return nil
+}
+
+// EmbedMessages embeds the turn's persisted user message — and the
+// assistant reply when Assistant is set — in one batch and re-stores them
+// with their vectors, making them findable by cross-thread recall
+// (Store.SearchMessages). Runs after PersistMessages; re-storing relies on
+// StoreMessage upserting by ID.
+type EmbedMessages struct {
+	Assistant bool
+}
+
+func (e EmbedMessages) Process(ctx context.Context, in *IngestContext) error {
+	if in.Embedding == nil || in.Store == nil {
+		return nil
+	}
+	var (
+		msgs  []core.Message
+		texts []string
+	)
+	for _, msg := range in.Messages {
+		if msg.Content == "" || (msg.Role == "assistant" && !e.Assistant) {
+			continue
+		}
+		msgs = append(msgs, msg)
+		texts = append(texts, msg.Content)
+	}
+	if len(texts) == 0 {
+		return nil
+	}
+	embs, err := in.Embedding.Embed(ctx, texts)
+	if err != nil || len(embs) != len(texts) {
+		in.Logger.Warn("embed messages failed; messages stay unsearchable", "thread_id", in.Task.ThreadID, "error", err)
+		return nil
+	}
+	for i, msg := range msgs {
+		msg.Embedding = embs[i]
+		if err := in.Store.StoreMessage(ctx, msg); err != nil {
+			in.Logger.Error("store message embedding failed", "message_id", msg.ID, "error", err)
+		}
+	}
 	return nil
 }
 
@@ -201,6 +288,9 @@ var trivialMessages = []string{
 	"lol", "haha", "wkwk", "wkwkwk",
 	"hmm", "hm", "oh", "ah",
 	"good", "great", "cool", "yep", "nope",
+	"hi", "hello", "hey", "halo", "hai", "hello there", "hi there",
+	"good morning", "good afternoon", "good evening", "good night",
+	"selamat pagi", "selamat siang", "selamat malam",
 }
 
 const extractFactsPrompt = `You are a memory extraction system. Given a conversation between a user and an assistant, extract factual information ABOUT THE USER.
@@ -242,19 +332,148 @@ type rawFact struct {
 }
 
 // FactExtractor runs LLM-driven extraction and appends Kind=fact candidates.
-type FactExtractor struct{}
+type FactExtractor struct {
+	// Filter decides from the user's message whether the turn is worth an
+	// extraction call. Nil uses the default heuristic, which skips short
+	// messages, acknowledgements, and greetings.
+	Filter func(userText string) bool
+
+	// Batch, when non-nil, defers extraction until several turns that pass
+	// Filter have accumulated in a thread, then extracts from all of them in
+	// one call. Nil extracts on every turn that passes Filter.
+	Batch *FactBatch
+}
+
+// FactBatch collects turns per thread for a batched FactExtractor. Pending
+// turns are held in memory only: turns still pending when the process exits
+// are never extracted. At most factBatchMaxThreads threads are tracked; a
+// new thread beyond that evicts the least recently updated one, dropping its
+// pending turns. Safe for concurrent use.
+type FactBatch struct {
+	size    int
+	mu      sync.Mutex
+	pending map[string]*pendingTurns // thread ID -> formatted turns
+	seq     uint64                   // bumped on every update, for eviction order
+}
+
+// pendingTurns is one thread's queued turns.
+type pendingTurns struct {
+	turns []string
+	seq   uint64
+}
+
+// factBatchMaxThreads bounds how many threads a FactBatch tracks, so threads
+// that never fill a batch (one-off chats, pruned threads) can't grow it
+// without limit.
+const factBatchMaxThreads = 1000
+
+// NewFactBatch returns a FactBatch that releases a thread's turns once size
+// of them are pending. Panics if size < 1.
+func NewFactBatch(size int) *FactBatch {
+	if size < 1 {
+		panic("memory.NewFactBatch: size must be >= 1")
+	}
+	return &FactBatch{size: size, pending: make(map[string]*pendingTurns)}
+}
+
+// add queues turn for threadID and returns the thread's pending turns once
+// the batch is full, clearing them. It returns nil while the batch fills.
+// evicted names a thread whose pending turns were dropped to make room, or
+// is "" when none was.
+func (b *FactBatch) add(threadID, turn string) (ready []string, evicted string) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+	p, ok := b.pending[threadID]
+	if !ok {
+		evicted = b.evictLocked()
+		p = &pendingTurns{}
+		b.pending[threadID] = p
+	}
+	p.turns = append(p.turns, turn)
+	if len(p.turns) < b.size {
+		b.seq++
+		p.seq = b.seq
+		return nil, evicted
+	}
+	delete(b.pending, threadID)
+	return p.turns, evicted
+}
+
+// restore puts turns from a failed extraction back at the front of
+// threadID's queue, so the next full batch retries them. At most size turns
+// beyond the current queue are kept, dropping the oldest, so a provider that
+// keeps failing can't grow a thread's queue without limit.
+func (b *FactBatch) restore(threadID string, turns []string) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+	p, ok := b.pending[threadID]
+	if !ok {
+		b.evictLocked()
+		p = &pendingTurns{}
+		b.pending[threadID] = p
+	}
+	if len(turns) > b.size {
+		turns = turns[len(turns)-b.size:]
+	}
+	p.turns = append(slices.Clip(turns), p.turns...)
+	b.seq++
+	p.seq = b.seq
+}
+
+// evictLocked drops the least recently updated thread when the batch is at
+// capacity and returns its ID, or "" when there is room. b.mu must be held.
+func (b *FactBatch) evictLocked() string {
+	if len(b.pending) < factBatchMaxThreads {
+		return ""
+	}
+	var oldest string
+	var oldestSeq uint64
+	for id, p := range b.pending {
+		if oldest == "" || p.seq < oldestSeq {
+			oldest, oldestSeq = id, p.seq
+		}
+	}
+	delete(b.pending, oldest)
+	return oldest
+}
 
-func (FactExtractor) Process(ctx context.Context, in *IngestContext) error {
-	if in.Provider == nil || !shouldExtractFacts(in.UserText) {
+func (f FactExtractor) Process(ctx context.Context, in *IngestContext) error {
+	if in.Provider == nil {
+		return nil
+	}
+	should := f.Filter
+	if should == nil {
+		should = shouldExtractFacts
+	}
+	if !should(in.UserText) {
 		return nil
 	}
+	conversation := fmt.Sprintf("User: %s\nAssistant: %s", in.UserText, in.AsstText)
+	var batched []string
+	if f.Batch != nil {
+		turns, evicted := f.Batch.add(in.Task.ThreadID, conversation)
+		if evicted != "" {
+			in.Logger.Warn("fact batch full; dropped pending turns of least recent thread", "thread_id", evicted)
+		}
+		if turns == nil {
+			return nil
+		}
+		batched = turns
+		conversation = strings.Join(turns, "\n\n")
+	}
 	resp, err := core.Chat(ctx, in.Provider, core.ChatRequest{
 		Messages: []core.ChatMessage{
 			core.SystemMessage(extractFactsPrompt),
-			core.UserMessage(fmt.Sprintf("User: %s\nAssistant: %s", in.UserText, in.AsstText)),
+			core.UserMessage(conversation),
 		},
 	})
 	if err != nil {
+		if batched != nil {
+			// Why: add already cleared the thread's queue; without this the
+			// whole batch would be lost to one transient provider error.
+			f.Batch.restore(in.Task.ThreadID, batched)
+			in.Logger.Warn("batched fact extraction failed; turns requeued", "thread_id", in.Task.ThreadID, "turns", len(batched), "error", err)
+		}
 		return nil
 	}
 	raw := parseRawFacts(resp.Content)
@@ -327,7 +546,7 @@ func shouldExtractFacts(text string) bool {
 	if len(trimmed) < 10 {
 		return false
 	}
-	lower := strings.ToLower(trimmed)
+	lower := strings.TrimRight(strings.ToLower(trimmed), "!.?~ ")
 	for _, s := range trivialMessages {
 		if lower == s {
 			return false
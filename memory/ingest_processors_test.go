@@ -4,6 +4,8 @@ package memory
 import (
 	"context"
 	"errors"
+	"fmt"
+	"strings"
 	"testing"
 
 	"github.com/nevindra/oasis/core"
@@ -69,6 +71,43 @@ func TestEmbedder_BackfillsEmbeddings(t *testing.T) {
 	}
 }
 
+func TestEmbedMessages(t *testing.T) {
+	for _, tt := range []struct {
+		assistant bool
+		want      map[string]bool // role → has embedding
+	}{
+		{false, map[string]bool{"user": true, "assistant": false}},
+		{true, map[string]bool{"user": true, "assistant": true}},
+	} {
+		store := newConformanceStore(t)
+		vecs := [][]float32{{1, 0}}
+		if tt.assistant {
+			vecs = append(vecs, []float32{0, 1})
+		}
+		in := &IngestContext{
+			Task:      core.AgentTask{ThreadID: "t1"},
+			UserText:  "what is my plan",
+			AsstText:  "your plan is to ship on Friday",
+			Store:     store,
+			Embedding: &fakeEmbedder{out: vecs},
+			Logger:    discardLogger(),
+		}
+		chain := []IngestProcessor{PersistMessages{}, EmbedMessages{Assistant: tt.assistant}}
+		if err := runIngestPipeline(context.Background(), in, chain); err != nil {
+			t.Fatal(err)
+		}
+		msgs := store.messages["t1"]
+		if len(msgs) != 2 {
+			t.Fatalf("Assistant=%v: %d messages stored, want 2 (re-store must upsert)", tt.assistant, len(msgs))
+		}
+		for _, msg := range msgs {
+			if got := len(msg.Embedding) > 0; got != tt.want[msg.Role] {
+				t.Errorf("Assistant=%v: %s message embedded = %v, want %v", tt.assistant, msg.Role, got, tt.want[msg.Role])
+			}
+		}
+	}
+}
+
 type fakeEmbedder struct {
 	out [][]float32
 	err error
@@ -112,14 +151,22 @@ func TestUpserter_WritesAllCandidates(t *testing.T) {
 
 type fakeProvider struct {
 	response string
+	err      error
 	called   bool
+	calls    int
+	lastReq  core.ChatRequest
 }
 
-func (f *fakeProvider) ChatStream(_ context.Context, _ core.ChatRequest, ch chan<- core.StreamEvent) (core.ChatResponse, error) {
+func (f *fakeProvider) ChatStream(_ context.Context, req core.ChatRequest, ch chan<- core.StreamEvent) (core.ChatResponse, error) {
 	f.called = true
+	f.calls++
+	f.lastReq = req
 	if ch != nil {
 		close(ch)
 	}
+	if f.err != nil {
+		return core.ChatResponse{}, f.err
+	}
 	return core.ChatResponse{Content: f.response}, nil
 }
 
@@ -169,6 +216,125 @@ func TestFactExtractor_SkipsTrivial(t *testing.T) {
 	}
 }
 
+func TestFactExtractor_SkipsGreetings(t *testing.T) {
+	for _, text := range []string{"Good morning!", "hello there", "Hi!!"} {
+		provider := &fakeProvider{}
+		in := &IngestContext{UserText: text, Provider: provider, Logger: discardLogger()}
+		_ = (FactExtractor{}).Process(context.Background(), in)
+		if provider.called {
+			t.Errorf("provider called for greeting %q", text)
+		}
+	}
+}
+
+func TestShouldExtractFacts_GreetingList(t *testing.T) {
+	// All longer than the 10-byte floor, so only the greeting list skips them.
+	for _, text := range []string{"hello there!", "hi!!!!!!!!", "Good evening.", "selamat pagi~"} {
+		if shouldExtractFacts(text) {
+			t.Errorf("shouldExtractFacts(%q) = true, want false", text)
+		}
+	}
+	if !shouldExtractFacts("hello, I just moved to Bali") {
+		t.Error("a greeting followed by content should still be extracted")
+	}
+}
+
+func TestFactExtractor_Batch(t *testing.T) {
+	provider := &fakeProvider{response: `[{"fact": "User lives in Bali", "category": "personal"}]`}
+	f := FactExtractor{Batch: NewFactBatch(2)}
+	turn := func(thread, text string) *IngestContext {
+		in := &IngestContext{Task: core.AgentTask{ThreadID: thread}, UserText: text, AsstText: "Noted.", Provider: provider, Logger: discardLogger()}
+		_ = f.Process(context.Background(), in)
+		return in
+	}
+
+	if in := turn("t1", "I moved to Bali last month."); provider.calls != 0 || len(in.Candidates) != 0 {
+		t.Fatalf("first turn: calls = %d, candidates = %d; want extraction deferred", provider.calls, len(in.Candidates))
+	}
+	turn("t2", "My sister works at a bank.")
+	turn("t1", "ok") // trivial: not counted toward the batch
+	if provider.calls != 0 {
+		t.Fatalf("calls = %d before any thread filled its batch", provider.calls)
+	}
+	in := turn("t1", "I work remotely as a designer.")
+	if provider.calls != 1 || len(in.Candidates) != 1 {
+		t.Fatalf("calls = %d, candidates = %d; want one batched extraction", provider.calls, len(in.Candidates))
+	}
+	prompt := provider.lastReq.Messages[1].Content
+	if !strings.Contains(prompt, "moved to Bali") || !strings.Contains(prompt, "designer") || strings.Contains(prompt, "sister") {
+		t.Errorf("batched prompt should hold both t1 turns only, got %q", prompt)
+	}
+}
+
+func TestFactExtractor_BatchRequeuesOnFailure(t *testing.T) {
+	provider := &fakeProvider{err: errors.New("provider down")}
+	f := FactExtractor{Batch: NewFactBatch(2)}
+	turn := func(text string) *IngestContext {
+		in := &IngestContext{Task: core.AgentTask{ThreadID: "t1"}, UserText: text, AsstText: "Noted.", Provider: provider, Logger: discardLogger()}
+		_ = f.Process(context.Background(), in)
+		return in
+	}
+	turn("I moved to Bali last month.")
+	turn("I work remotely as a designer.")
+	if provider.calls != 1 {
+		t.Fatalf("calls = %d, want the failed batched call", provider.calls)
+	}
+
+	provider.err = nil
+	provider.response = `[{"fact": "User lives in Bali", "category": "personal"}]`
+	turn("My sister works at a bank.")
+	if provider.calls != 2 {
+		t.Fatalf("calls = %d, want the requeued batch retried", provider.calls)
+	}
+	prompt := provider.lastReq.Messages[1].Content
+	for _, want := range []string{"moved to Bali", "designer", "sister"} {
+		if !strings.Contains(prompt, want) {
+			t.Errorf("retried prompt lost %q: %q", want, prompt)
+		}
+	}
+}
+
+func TestFactBatch_EvictsLeastRecentThread(t *testing.T) {
+	b := NewFactBatch(3)
+	for i := range factBatchMaxThreads {
+		if _, evicted := b.add(fmt.Sprintf("t%d", i), "turn"); evicted != "" {
+			t.Fatalf("evicted %q before the cap was reached", evicted)
+		}
+	}
+	// Touching t0 makes t1 the least recently updated thread.
+	if _, evicted := b.add("t0", "turn"); evicted != "" {
+		t.Fatalf("evicted %q when adding to a tracked thread", evicted)
+	}
+	_, evicted := b.add("new", "turn")
+	if evicted != "t1" {
+		t.Errorf("evicted = %q, want t1", evicted)
+	}
+	if len(b.pending) != factBatchMaxThreads {
+		t.Errorf("tracking %d threads, want the cap %d", len(b.pending), factBatchMaxThreads)
+	}
+}
+
+func TestFactExtractor_CustomFilter(t *testing.T) {
+	provider := &fakeProvider{response: `[]`}
+	var seen string
+	f := FactExtractor{Filter: func(text string) bool { seen = text; return false }}
+	in := &IngestContext{UserText: "I moved to Jakarta last month.", Provider: provider, Logger: discardLogger()}
+	_ = f.Process(context.Background(), in)
+	if seen != in.UserText {
+		t.Errorf("filter saw %q, want the user text", seen)
+	}
+	if provider.called {
+		t.Fatal("provider called although the filter rejected the turn")
+	}
+
+	f.Filter = func(string) bool { return true }
+	in = &IngestContext{UserText: "ok", Provider: provider, Logger: discardLogger()}
+	_ = f.Process(context.Background(), in)
+	if !provider.called {
+		t.Fatal("custom filter should override the trivial-message heuristic")
+	}
+}
+
 // --- Deduper tests ---
 
 // panicEmbedder fails the test if Embed is ever called.
@@ -198,6 +364,46 @@ func TestDeduper_SkipsWhenNoSupersedes(t *testing.T) {
 	// Test passes if panicEmbedder.Embed was never called.
 }
 
+func TestThreadRetention_PrunesOldestThreads(t *testing.T) {
+	ctx := context.Background()
+	store := newConformanceStore(t)
+	defer store.Close()
+	for i, id := range []string{"old", "mid", "recent"} {
+		_ = store.CreateThread(ctx, core.Thread{ID: id, ChatID: "c1", UpdatedAt: int64(i + 1)})
+		_ = store.StoreMessage(ctx, core.Message{ThreadID: id, Role: "user", Content: "hi"})
+	}
+	_ = store.CreateThread(ctx, core.Thread{ID: "other", ChatID: "c2", UpdatedAt: 1})
+
+	in := &IngestContext{
+		Task:   core.AgentTask{ThreadID: "new", ChatID: "c1"},
+		Store:  store,
+		Logger: discardLogger(),
+	}
+	chain := []IngestProcessor{EnsureThread{}, ThreadRetention{Max: 2}}
+	if err := runIngestPipeline(ctx, in, chain); err != nil {
+		t.Fatal(err)
+	}
+
+	for id, want := range map[string]bool{"new": true, "recent": true, "mid": false, "old": false, "other": true} {
+		if _, err := store.GetThread(ctx, id); (err == nil) != want {
+			t.Errorf("thread %q kept = %v, want %v", id, err == nil, want)
+		}
+	}
+	if n := len(store.messages["old"]); n != 0 {
+		t.Errorf("pruned thread still has %d messages", n)
+	}
+
+	// A turn on an existing thread never prunes.
+	_ = store.CreateThread(ctx, core.Thread{ID: "extra", ChatID: "c1", UpdatedAt: 1})
+	in = &IngestContext{Task: core.AgentTask{ThreadID: "new", ChatID: "c1"}, Store: store, Logger: discardLogger()}
+	if err := runIngestPipeline(ctx, in, chain); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := store.GetThread(ctx, "extra"); err != nil {
+		t.Error("thread pruned on a turn that created none")
+	}
+}
+
 // --- TitleGenerator tests ---
 
 func TestTitleGenerator_SkipsWhenThreadNotCreated(t *testing.T) {
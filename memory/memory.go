@@ -46,6 +46,9 @@ type AgentMemory struct {
 	// Recall knobs
 	semanticRecall   bool
 	semanticMinScore float32
+	semanticTopK     int
+	semanticMaxAge   time.Duration
+	embedAssistant   bool
 	recallKinds      []core.MemoryKind
 	recallTopK       int
 
@@ -54,7 +57,10 @@ type AgentMemory struct {
 	workingMemoryScope core.MemoryScopeKind
 
 	// Lifecycle
-	autoTitle bool
+	autoTitle         bool
+	factFilter        func(userText string) bool // nil = shouldExtractFacts
+	factBatch         *FactBatch                 // nil = extract every turn
+	maxThreadsPerChat int
 
 	// Compaction (history-shrink). Trigger lives in the agent loop; these
 	// fields are mirrored here so processors / callers can introspect them.
@@ -116,6 +122,9 @@ type AgentMemoryConfig struct {
 
 	SemanticRecall   bool
 	SemanticMinScore float32
+	SemanticTopK     int
+	SemanticMaxAge   time.Duration
+	EmbedAssistant   bool // also embed assistant replies for recall
 	RecallKinds      []core.MemoryKind
 	RecallTopK       int
 
@@ -124,6 +133,20 @@ type AgentMemoryConfig struct {
 
 	AutoTitle bool
 
+	// FactFilter gates per-turn fact extraction on the user's message. Nil
+	// uses the default heuristic. See WithFactExtractionFilter.
+	FactFilter func(userText string) bool
+
+	// FactBatchTurns batches fact extraction across this many qualifying
+	// turns per thread. 0 or 1 extracts every turn. See
+	// WithFactExtractionBatch.
+	FactBatchTurns int
+
+	// MaxThreadsPerChat caps how many threads a chat keeps; the least
+	// recently updated are deleted when a new one is created. 0 = unbounded.
+	// See WithMaxThreadsPerChat.
+	MaxThreadsPerChat int
+
 	// Compaction: when stored history exceeds CompactThreshold × window,
 	// the trigger (in the agent loop) calls Compactor.Compact. The trigger
 	// stays framework-level; policy lives in the Compactor implementation.
@@ -169,11 +192,19 @@ func (m *AgentMemory) Init(cfg AgentMemoryConfig) {
 	m.protectedTools = cfg.ProtectedTools
 	m.semanticRecall = cfg.SemanticRecall
 	m.semanticMinScore = cfg.SemanticMinScore
+	m.semanticTopK = cfg.SemanticTopK
+	m.semanticMaxAge = cfg.SemanticMaxAge
+	m.embedAssistant = cfg.EmbedAssistant
 	m.recallKinds = cfg.RecallKinds
 	m.recallTopK = cfg.RecallTopK
 	m.workingMemory = cfg.WorkingMemory
 	m.workingMemoryScope = cfg.WorkingMemoryScope
 	m.autoTitle = cfg.AutoTitle
+	m.factFilter = cfg.FactFilter
+	if cfg.FactBatchTurns > 1 {
+		m.factBatch = NewFactBatch(cfg.FactBatchTurns)
+	}
+	m.maxThreadsPerChat = cfg.MaxThreadsPerChat
 	m.compactor = cfg.Compactor
 	m.compactThreshold = cfg.CompactThreshold
 	m.compressModel = cfg.CompressModel
@@ -237,13 +268,21 @@ const persistTimeout = 30 * time.Second
 const syncPersistTimeout = 5 * time.Second
 
 // syncIngestChain returns the processors that must complete before a turn is
-// considered durable: the thread row and the user/assistant message rows.
-// DB-only, no LLM or embedding calls — cheap enough to run inline.
+// considered durable: the thread row and the user/assistant message rows,
+// plus thread retention when configured. DB-only, no LLM or embedding calls
+// — cheap enough to run inline.
 func (m *AgentMemory) syncIngestChain() []IngestProcessor {
-	return []IngestProcessor{
+	chain := []IngestProcessor{
 		EnsureThread{},
 		PersistMessages{},
 	}
+	// Why: inline rather than in the async chain, which is skipped under
+	// backpressure — retention must not silently stop applying under load.
+	// It only does work on turns that create a thread.
+	if m.maxThreadsPerChat > 0 {
+		chain = append(chain, ThreadRetention{Max: m.maxThreadsPerChat})
+	}
+	return chain
 }
 
 // asyncIngestChain returns the enrichment processors that may run in the
@@ -254,11 +293,16 @@ func (m *AgentMemory) syncIngestChain() []IngestProcessor {
 func (m *AgentMemory) asyncIngestChain() []IngestProcessor {
 	var chain []IngestProcessor
 	if m.provider != nil {
-		chain = append(chain, FactExtractor{})
+		chain = append(chain, FactExtractor{Filter: m.factFilter, Batch: m.factBatch})
 	}
 	if m.embedding != nil {
 		chain = append(chain, Deduper{}, Embedder{})
 	}
+	// Why: messages are only ever searched by cross-thread recall, so they
+	// cost an embedding call only when recall is on.
+	if m.embedding != nil && m.semanticRecall {
+		chain = append(chain, EmbedMessages{Assistant: m.embedAssistant})
+	}
 	// Upserter and DecayProbabilistic act only on the item store; without
 	// one they are no-ops, and skipping them lets PersistTurn avoid spawning
 	// an enrichment goroutine at all on messages-only deployments.
@@ -3,6 +3,7 @@ package memory
 
 import (
 	"log/slog"
+	"time"
 
 	"github.com/nevindra/oasis/core"
 )
@@ -112,6 +113,31 @@ func WithSemanticRecallMinScore(s float32) Option {
 	return func(c *AgentMemoryConfig) { c.SemanticMinScore = s }
 }
 
+// WithSemanticRecallTopK sets how many past messages cross-thread recall
+// searches (default 5), independently of WithRecallTopK, which bounds
+// memory items. Messages from the current thread or below the min score are
+// dropped after the search, so fewer may be injected.
+func WithSemanticRecallTopK(k int) Option {
+	return func(c *AgentMemoryConfig) { c.SemanticTopK = k }
+}
+
+// WithSemanticRecallMaxAge limits cross-thread recall to messages written
+// within d, so stale conversations stop resurfacing. Older matches are
+// dropped after the search, like those below the min score; to leave room
+// for them, the search fetches three times the recall top-K and at most
+// top-K recent matches are kept. d <= 0 (the default) applies no bound.
+func WithSemanticRecallMaxAge(d time.Duration) Option {
+	return func(c *AgentMemoryConfig) { c.SemanticMaxAge = d }
+}
+
+// WithEmbedAssistantMessages makes cross-thread recall cover the agent's own
+// replies, not just what the user wrote, so something the agent told the
+// user in another thread can be recalled. Costs a larger embedding batch per
+// turn; off by default. Has no effect without WithSemanticRecall.
+func WithEmbedAssistantMessages() Option {
+	return func(c *AgentMemoryConfig) { c.EmbedAssistant = true }
+}
+
 // WithRecallKinds configures which MemoryItem kinds are included in BatchedRecall.
 // Defaults to [KindFact] when not set.
 func WithRecallKinds(kinds ...core.MemoryKind) Option {
@@ -139,6 +165,35 @@ func WithWorkingMemoryScope(s core.MemoryScopeKind) Option {
 // WithAutoTitle enables LLM-driven thread title generation on the first turn.
 func WithAutoTitle() Option { return func(c *AgentMemoryConfig) { c.AutoTitle = true } }
 
+// WithFactExtractionFilter replaces the heuristic that decides, from the
+// user's message, whether a turn runs LLM fact extraction. The default skips
+// messages under 10 bytes, acknowledgements, and greetings. Return false
+// more often to cut extraction cost (e.g. only when the message mentions the
+// user), or always to disable extraction while keeping a provider for
+// WithAutoTitle.
+func WithFactExtractionFilter(fn func(userText string) bool) Option {
+	return func(c *AgentMemoryConfig) { c.FactFilter = fn }
+}
+
+// WithMaxThreadsPerChat keeps at most n threads per chat. When a turn
+// creates a new thread, the chat's least recently updated threads beyond n
+// are deleted along with their messages. Turns without a ChatID are not
+// pruned. n <= 0 (the default) keeps every thread.
+func WithMaxThreadsPerChat(n int) Option {
+	return func(c *AgentMemoryConfig) { c.MaxThreadsPerChat = n }
+}
+
+// WithFactExtractionBatch runs fact extraction once per n turns that pass
+// the extraction filter in a thread, sending all n turns in a single LLM
+// call instead of one call per turn. Pending turns live in memory, so turns
+// still waiting when the process exits are not extracted; at most 1000
+// threads are tracked, the least recently active one dropped first. A failed
+// extraction call requeues its turns. n <= 1 extracts every turn (the
+// default).
+func WithFactExtractionBatch(n int) Option {
+	return func(c *AgentMemoryConfig) { c.FactBatchTurns = n }
+}
+
 // WithTools registers agent-callable memory tools. Default OFF; pass
 // the tools you want — typically constructed from an AgentMemory like:
 //
@@ -7,14 +7,19 @@ import (
 	"strings"
 
 	"github.com/nevindra/oasis/core"
+	"github.com/nevindra/oasis/rag"
 )
 
 const (
 	defaultMaxHistory             = 10
 	defaultKeepRecent             = 3
 	defaultSemanticRecallMinScore = float32(0.60)
+	defaultSemanticRecallTopK     = 5
+	maxAgeOverfetch               = 3 // SearchMessages multiplier when MaxAge is set
 	maxRecallContentLen           = 500
 	defaultRecallTopK             = 8
+	defaultKnowledgeTopK          = 5
+	maxKnowledgeContentLen        = 2000
 )
 
 // RetrieveProcessor transforms a RetrieveContext on the hot path.
@@ -34,6 +39,7 @@ type RetrieveContext struct {
 	Selected    map[core.MemoryKind][]core.MemoryItem // by Kind, set by BatchedRecall
 	Pinned      []core.MemoryItem
 	CrossThread []core.ScoredMessage
+	Knowledge   []rag.RetrievalResult // set by RecallKnowledge
 
 	SystemPrompt string
 	PromptParts  []string
@@ -140,7 +146,11 @@ func (m *AgentMemory) defaultRetrieveChain() []RetrieveProcessor {
 		})
 	}
 	if m.semanticRecall {
-		chain = append(chain, RecallCrossThread{MinScore: m.semanticMinScore})
+		chain = append(chain, RecallCrossThread{
+			MinScore: m.semanticMinScore,
+			TopK:     m.semanticTopK,
+			MaxAge:   m.semanticMaxAge,
+		})
 	}
 	if m.maxTokens > 0 {
 		trimProc := TrimToBudget{
@@ -5,8 +5,10 @@ import (
 	"context"
 	"strings"
 	"testing"
+	"time"
 
 	"github.com/nevindra/oasis/core"
+	"github.com/nevindra/oasis/rag"
 )
 
 func TestBuildMessages_Minimal(t *testing.T) {
@@ -213,3 +215,169 @@ func TestBuildMessages_SystemStableAcrossCalls(t *testing.T) {
 		t.Errorf("system message differs between calls (cache miss):\ncall1: %q\ncall2: %q", sys1.Content, sys2.Content)
 	}
 }
+
+// topKStore records the topK passed to SearchMessages.
+type topKStore struct {
+	*testStore
+	gotK int
+}
+
+func (s *topKStore) SearchMessages(_ context.Context, _ []float32, topK int, _ string) ([]core.ScoredMessage, error) {
+	s.gotK = topK
+	return nil, nil
+}
+
+func TestRecallCrossThread_TopK(t *testing.T) {
+	for _, tt := range []struct {
+		topK, want int
+	}{{0, defaultSemanticRecallTopK}, {12, 12}} {
+		store := &topKStore{testStore: newConformanceStore(t)}
+		in := &RetrieveContext{HistoryStore: store, Embedding: []float32{1}}
+		if err := (RecallCrossThread{TopK: tt.topK}).Process(context.Background(), in); err != nil {
+			t.Fatal(err)
+		}
+		if store.gotK != tt.want {
+			t.Errorf("TopK %d: SearchMessages topK = %d, want %d", tt.topK, store.gotK, tt.want)
+		}
+	}
+}
+
+type scoredStore struct {
+	*testStore
+	results []core.ScoredMessage
+	gotK    int
+}
+
+func (s *scoredStore) SearchMessages(_ context.Context, _ []float32, k int, _ string) ([]core.ScoredMessage, error) {
+	s.gotK = k
+	return s.results, nil
+}
+
+func TestRecallCrossThread_MaxAge(t *testing.T) {
+	now := time.Now()
+	store := &scoredStore{testStore: newConformanceStore(t), results: []core.ScoredMessage{
+		{Message: core.Message{ThreadID: "t0", Role: "user", Content: "recent plan", CreatedAt: now.Add(-time.Hour).Unix()}, Score: 0.9},
+		{Message: core.Message{ThreadID: "t0", Role: "user", Content: "stale plan", CreatedAt: now.Add(-90 * 24 * time.Hour).Unix()}, Score: 0.9},
+	}}
+	in := &RetrieveContext{HistoryStore: store, Embedding: []float32{1}, Task: core.AgentTask{ThreadID: "t1"}}
+	if err := (RecallCrossThread{MaxAge: 30 * 24 * time.Hour}).Process(context.Background(), in); err != nil {
+		t.Fatal(err)
+	}
+	got := strings.Join(in.PromptParts, "")
+	if !strings.Contains(got, "recent plan") || strings.Contains(got, "stale plan") {
+		t.Errorf("recall = %q, want only the message within MaxAge", got)
+	}
+}
+
+func TestRecallCrossThread_MaxAgeOverfetches(t *testing.T) {
+	now := time.Now()
+	msg := func(content string, age time.Duration) core.ScoredMessage {
+		return core.ScoredMessage{Message: core.Message{ThreadID: "t0", Role: "user", Content: content, CreatedAt: now.Add(-age).Unix()}, Score: 0.9}
+	}
+	store := &scoredStore{testStore: newConformanceStore(t), results: []core.ScoredMessage{
+		msg("stale one", 90*24*time.Hour),
+		msg("stale two", 80*24*time.Hour),
+		msg("recent one", time.Hour),
+		msg("recent two", 2*time.Hour),
+		msg("recent three", 3*time.Hour),
+	}}
+	in := &RetrieveContext{HistoryStore: store, Embedding: []float32{1}, Task: core.AgentTask{ThreadID: "t1"}}
+	if err := (RecallCrossThread{TopK: 2, MaxAge: 30 * 24 * time.Hour}).Process(context.Background(), in); err != nil {
+		t.Fatal(err)
+	}
+	if store.gotK != 6 {
+		t.Errorf("SearchMessages topK = %d, want 6 (over-fetch for MaxAge)", store.gotK)
+	}
+	got := strings.Join(in.PromptParts, "")
+	if !strings.Contains(got, "recent one") || !strings.Contains(got, "recent two") {
+		t.Errorf("recall = %q, want the recent matches behind the stale ones", got)
+	}
+	if strings.Contains(got, "stale") || strings.Contains(got, "recent three") {
+		t.Errorf("recall = %q, want at most TopK recent matches", got)
+	}
+}
+
+type chunkStore struct {
+	*testStore
+	chunks []core.ScoredChunk
+}
+
+func (s *chunkStore) SearchChunks(context.Context, []float32, int, ...core.ChunkFilter) ([]core.ScoredChunk, error) {
+	return s.chunks, nil
+}
+
+func TestRecallKnowledge_HybridRetrieverSkipsUnrelated(t *testing.T) {
+	store := &chunkStore{testStore: newConformanceStore(t), chunks: []core.ScoredChunk{
+		{Chunk: core.Chunk{ID: "c1", Content: "The office closes at 6pm."}, Score: 0.21},
+		{Chunk: core.Chunk{ID: "c2", Content: "Parking is on level 2."}, Score: 0.12},
+	}}
+	emb := &fakeEmbedder{out: [][]float32{{1, 0}}}
+
+	// Rank fusion scores the nearest unrelated chunk highly, so a MinScore
+	// gate on its own lets it through.
+	in := &RetrieveContext{Task: core.AgentTask{Input: "hey, how's it going?"}}
+	if err := (RecallKnowledge{Retriever: rag.NewHybridRetriever(store, emb), MinScore: 0.5}).Process(context.Background(), in); err != nil {
+		t.Fatal(err)
+	}
+	if len(in.Knowledge) == 0 {
+		t.Fatal("expected the fused-score gate to pass the top unrelated chunk")
+	}
+
+	in = &RetrieveContext{Task: core.AgentTask{Input: "hey, how's it going?"}}
+	r := RecallKnowledge{Retriever: rag.NewHybridRetriever(store, emb, rag.WithMinVectorScore(0.5))}
+	if err := r.Process(context.Background(), in); err != nil {
+		t.Fatal(err)
+	}
+	if len(in.PromptParts) != 0 || len(in.Knowledge) != 0 {
+		t.Errorf("unrelated passages injected: %v", in.PromptParts)
+	}
+
+	store.chunks = append([]core.ScoredChunk{{Chunk: core.Chunk{ID: "c0", Content: "Refunds are issued within 14 days."}, Score: 0.82}}, store.chunks...)
+	in = &RetrieveContext{Task: core.AgentTask{Input: "how long do refunds take?"}}
+	if err := r.Process(context.Background(), in); err != nil {
+		t.Fatal(err)
+	}
+	if len(in.Knowledge) != 1 || in.Knowledge[0].ChunkID != "c0" {
+		t.Errorf("Knowledge = %+v, want only the refund passage", in.Knowledge)
+	}
+}
+
+type stubRetriever []rag.RetrievalResult
+
+func (s stubRetriever) Retrieve(context.Context, string, int) ([]rag.RetrievalResult, error) {
+	return s, nil
+}
+
+func TestRecallKnowledge_InjectsOnlyRelevantPassages(t *testing.T) {
+	retriever := stubRetriever{
+		{Content: "Refunds are issued within 14 days.", Score: 0.82, DocumentTitle: "Refund policy"},
+		{Content: "The office closes at 6pm.", Score: 0.21, DocumentTitle: "Office hours"},
+	}
+	var m AgentMemory
+	m.Init(AgentMemoryConfig{
+		RetrieveProcs: []RetrieveProcessor{RecallKnowledge{Retriever: retriever, MinScore: 0.5}},
+		Logger:        discardLogger(),
+	})
+	msgs := m.BuildMessages(context.Background(), "agent", "", core.AgentTask{Input: "how long do refunds take?"})
+	var block string
+	for _, msg := range msgs {
+		if strings.HasPrefix(msg.Content, "<context>") {
+			block = msg.Content
+		}
+	}
+	if !strings.Contains(block, "[Refund policy]: Refunds are issued within 14 days.") {
+		t.Errorf("relevant passage not injected: %q", block)
+	}
+	if strings.Contains(block, "office") {
+		t.Errorf("low-score passage injected: %q", block)
+	}
+
+	// Nothing relevant: no context block at all.
+	in := &RetrieveContext{Task: core.AgentTask{Input: "hi"}}
+	if err := (RecallKnowledge{Retriever: retriever[1:], MinScore: 0.5}).Process(context.Background(), in); err != nil {
+		t.Fatal(err)
+	}
+	if len(in.PromptParts) != 0 || len(in.Knowledge) != 0 {
+		t.Errorf("irrelevant passages injected: %v", in.PromptParts)
+	}
+}
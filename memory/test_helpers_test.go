@@ -5,6 +5,7 @@ import (
 	"context"
 	"errors"
 	"log/slog"
+	"sort"
 	"sync"
 
 	"github.com/nevindra/oasis/core"
@@ -51,8 +52,20 @@ func (s *testStore) GetThread(_ context.Context, id string) (core.Thread, error)
 	return t, nil
 }
 
-func (s *testStore) ListThreads(_ context.Context, _ string, _ int) ([]core.Thread, error) {
-	return nil, nil
+func (s *testStore) ListThreads(_ context.Context, chatID string, limit int) ([]core.Thread, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	var out []core.Thread
+	for _, t := range s.threads {
+		if t.ChatID == chatID {
+			out = append(out, t)
+		}
+	}
+	sort.Slice(out, func(i, j int) bool { return out[i].UpdatedAt > out[j].UpdatedAt })
+	if limit > 0 && len(out) > limit {
+		out = out[:limit]
+	}
+	return out, nil
 }
 
 func (s *testStore) UpdateThread(_ context.Context, t core.Thread) error {
@@ -62,13 +75,26 @@ func (s *testStore) UpdateThread(_ context.Context, t core.Thread) error {
 	return nil
 }
 
-func (s *testStore) DeleteThread(_ context.Context, _ string) error { return nil }
+func (s *testStore) DeleteThread(_ context.Context, id string) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	delete(s.threads, id)
+	delete(s.messages, id)
+	return nil
+}
 
 // --- core.Store: Messages ---
 
 func (s *testStore) StoreMessage(_ context.Context, m core.Message) error {
 	s.mu.Lock()
 	defer s.mu.Unlock()
+	// Upsert by ID, like the real stores.
+	for i, existing := range s.messages[m.ThreadID] {
+		if existing.ID == m.ID {
+			s.messages[m.ThreadID][i] = m
+			return nil
+		}
+	}
 	s.messages[m.ThreadID] = append(s.messages[m.ThreadID], m)
 	return nil
 }
@@ -12,7 +12,10 @@ import (
 )
 
 // RememberTool returns a core.AnyTool that lets the LLM save a memory item.
-// Schema: {content: string, kind?: string, scope?: string, tags?: []string, pinned?: bool}
+// Schema: {content?: string, facts?: []string, kind?: string, scope?: string, tags?: []string, pinned?: bool}
+// Each entry of facts is saved as its own item, so "I prefer tea, my dog is
+// Rex, I work at Acme" becomes three individually recallable facts instead of
+// one combined item. At least one of content and facts is required.
 func (m *AgentMemory) RememberTool() core.AnyTool { return rememberTool{m: m} }
 
 // RecallTool returns a core.AnyTool that lets the LLM search memory.
@@ -40,13 +43,14 @@ func (rememberTool) Name() string { return "memory.remember" }
 func (rememberTool) Definition() core.ToolDefinition {
 	return core.ToolDefinition{
 		Name:        "memory.remember",
-		Description: "Save a memory item for future recall. Args: content (required), kind (default 'fact'), scope ('thread'|'resource'|'agent'), tags, pinned.",
+		Description: "Save a memory item for future recall. Args: content, or facts (an array of short standalone statements, each saved separately — split a message stating several facts into one entry per fact), kind (default 'fact'), scope ('thread'|'resource'|'agent'), tags, pinned.",
 	}
 }
 
 func (t rememberTool) ExecuteRaw(ctx context.Context, args json.RawMessage) (core.ToolResult, error) {
 	var a struct {
 		Content string   `json:"content"`
+		Facts   []string `json:"facts,omitempty"`
 		Kind    string   `json:"kind,omitempty"`
 		Scope   string   `json:"scope,omitempty"`
 		Tags    []string `json:"tags,omitempty"`
@@ -55,27 +59,38 @@ func (t rememberTool) ExecuteRaw(ctx context.Context, args json.RawMessage) (cor
 	if err := json.Unmarshal(args, &a); err != nil {
 		return errResult("invalid args: " + err.Error()), nil
 	}
-	if a.Content == "" {
-		return errResult("invalid args: 'content' is required"), nil
+	var contents []string
+	for _, c := range append([]string{a.Content}, a.Facts...) {
+		if c = strings.TrimSpace(c); c != "" {
+			contents = append(contents, c)
+		}
+	}
+	if len(contents) == 0 {
+		return errResult("invalid args: 'content' or 'facts' is required"), nil
 	}
 	kind := core.MemoryKind(a.Kind)
 	if kind == "" {
 		kind = KindFact
 	}
 	scope := scopeFromStr(a.Scope)
-	item := core.MemoryItem{
-		ID:      core.NewID(),
-		Kind:    kind,
-		Content: a.Content,
-		Scope:   scope,
-		Source:  core.MemorySource{Kind: "tool"},
-		Tags:    a.Tags,
-		Pinned:  a.Pinned,
-	}
-	if err := t.m.Remember(ctx, item); err != nil {
-		return errResult("remember failed: " + err.Error()), nil
+	saved := make([]string, 0, len(contents))
+	for _, c := range contents {
+		item := core.MemoryItem{
+			ID:      core.NewID(),
+			Kind:    kind,
+			Content: c,
+			Scope:   scope,
+			Source:  core.MemorySource{Kind: "tool"},
+			Tags:    a.Tags,
+			Pinned:  a.Pinned,
+		}
+		if err := t.m.Remember(ctx, item); err != nil {
+			// Report what already landed so the LLM doesn't save it twice.
+			return errResult(fmt.Sprintf("remember failed after saving %d of %d: %v", len(saved), len(contents), err)), nil
+		}
+		saved = append(saved, string(kind)+"/"+item.ID)
 	}
-	return textResult(fmt.Sprintf("saved %s/%s", kind, item.ID)), nil
+	return textResult("saved " + strings.Join(saved, ", ")), nil
 }
 
 // --- recallTool ---
@@ -33,6 +33,33 @@ func TestRememberTool_WritesItem(t *testing.T) {
 	}
 }
 
+func TestRememberTool_FactsSavedSeparately(t *testing.T) {
+	store := newConformanceStore(t)
+	var m AgentMemory
+	m.Init(AgentMemoryConfig{Store: store, Logger: discardLogger()})
+
+	args, _ := json.Marshal(map[string]any{
+		"facts": []string{"User prefers tea", "User's dog is named Rex", " ", "User works at Acme"},
+	})
+	res, err := m.RememberTool().ExecuteRaw(context.Background(), args)
+	if err != nil || res.Error != "" {
+		t.Fatalf("ExecuteRaw = %v, %q", err, res.Error)
+	}
+	items, _ := store.List(context.Background(), core.MemoryFilter{Kinds: []core.MemoryKind{KindFact}})
+	got := map[string]bool{}
+	for _, it := range items {
+		got[it.Content] = true
+	}
+	if len(items) != 3 || !got["User prefers tea"] || !got["User's dog is named Rex"] || !got["User works at Acme"] {
+		t.Fatalf("want three separate facts, got %+v", items)
+	}
+
+	empty, _ := json.Marshal(map[string]any{"facts": []string{""}})
+	if res, _ := m.RememberTool().ExecuteRaw(context.Background(), empty); res.Error == "" {
+		t.Error("expected an error when no content or facts are given")
+	}
+}
+
 func TestRecallTool_ReturnsItems(t *testing.T) {
 	store := newConformanceStore(t)
 	must(t, store.Upsert(context.Background(), core.MemoryItem{
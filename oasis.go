@@ -128,6 +128,7 @@ var WithProcessors = agent.WithProcessors
 var WithHooks = agent.WithHooks
 var WithToolConfig = agent.WithToolConfig
 var Approval = agent.Approval
+var TruncateToolResult = agent.TruncateToolResult
 var WithInputHandler = agent.WithInputHandler
 var WithMiddleware = agent.WithMiddleware
 var WithSkills = agent.WithSkills
@@ -135,6 +136,8 @@ var WithActiveSkills = agent.WithActiveSkills
 var WithSkillCatalog = agent.WithSkillCatalog
 var WithEmbedding = agent.WithEmbedding
 var RetryMiddleware = agent.RetryMiddleware
+var CircuitBreakerMiddleware = agent.CircuitBreakerMiddleware
+var EmptyResponseRetryMiddleware = agent.EmptyResponseRetryMiddleware
 var WithOverrides = agent.WithOverrides
 
 // --- Run options (per-call) ---
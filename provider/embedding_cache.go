@@ -0,0 +1,142 @@
+package provider
+
+import (
+	"container/list"
+	"context"
+	"crypto/sha256"
+	"fmt"
+	"slices"
+	"sync"
+
+	"github.com/nevindra/oasis/core"
+)
+
+// defaultEmbeddingCacheSize is the entry limit used when NewCachedEmbedding
+// is given a non-positive size.
+const defaultEmbeddingCacheSize = 10000
+
+// CachedEmbedding wraps an EmbeddingProvider with a bounded in-memory LRU
+// cache, so identical texts — a re-ingested document, a repeated search
+// query — are embedded once. Only cache misses are sent to the inner
+// provider, in a single Embed call, and duplicates within one call are
+// embedded once.
+//
+// Entries are keyed by a SHA-256 of the text and live in the wrapper, so
+// each model's provider gets its own cache and switching models never
+// reuses stale vectors. Vectors are copied in and out, so callers may modify
+// them. Safe for concurrent use.
+//
+// The wrapper exposes only EmbeddingProvider; type assertions for optional
+// capabilities such as core.MultimodalEmbeddingProvider do not see through it.
+type CachedEmbedding struct {
+	inner core.EmbeddingProvider
+	size  int
+
+	mu      sync.Mutex
+	order   *list.List // front = most recently used
+	entries map[[sha256.Size]byte]*list.Element
+}
+
+// cacheEntry is the value stored in CachedEmbedding.order.
+type cacheEntry struct {
+	key [sha256.Size]byte
+	vec []float32
+}
+
+// NewCachedEmbedding wraps inner with an LRU cache holding up to size
+// vectors (default 10000 when size <= 0).
+//
+//	emb := provider.NewCachedEmbedding(openaicompat.NewEmbedding(key, model, baseURL, 1536), 50000)
+func NewCachedEmbedding(inner core.EmbeddingProvider, size int) *CachedEmbedding {
+	if size <= 0 {
+		size = defaultEmbeddingCacheSize
+	}
+	return &CachedEmbedding{
+		inner:   inner,
+		size:    size,
+		order:   list.New(),
+		entries: make(map[[sha256.Size]byte]*list.Element),
+	}
+}
+
+// Name delegates to the inner provider.
+func (c *CachedEmbedding) Name() string { return c.inner.Name() }
+
+// Dimensions delegates to the inner provider.
+func (c *CachedEmbedding) Dimensions() int { return c.inner.Dimensions() }
+
+// Len returns the number of cached vectors.
+func (c *CachedEmbedding) Len() int {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	return c.order.Len()
+}
+
+// Embed returns one vector per text, serving cached texts from memory and
+// embedding the rest with a single call to the inner provider.
+func (c *CachedEmbedding) Embed(ctx context.Context, texts []string) ([][]float32, error) {
+	vecs := make([][]float32, len(texts))
+	keys := make([][sha256.Size]byte, len(texts))
+	hit := make([]bool, len(texts))
+	var (
+		missTexts []string
+		missIdx   = make(map[[sha256.Size]byte]int) // key → index into missTexts
+	)
+
+	c.mu.Lock()
+	for i, text := range texts {
+		keys[i] = sha256.Sum256([]byte(text))
+		if el, ok := c.entries[keys[i]]; ok {
+			c.order.MoveToFront(el)
+			vecs[i] = slices.Clone(el.Value.(*cacheEntry).vec)
+			hit[i] = true
+			continue
+		}
+		if _, ok := missIdx[keys[i]]; !ok {
+			missIdx[keys[i]] = len(missTexts)
+			missTexts = append(missTexts, text)
+		}
+	}
+	c.mu.Unlock()
+
+	if len(missTexts) == 0 {
+		return vecs, nil
+	}
+	embedded, err := c.inner.Embed(ctx, missTexts)
+	if err != nil {
+		return nil, err
+	}
+	if len(embedded) != len(missTexts) {
+		return nil, &core.ErrLLM{Provider: c.inner.Name(), Message: fmt.Sprintf("embed returned %d vectors for %d texts", len(embedded), len(missTexts))}
+	}
+
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	for i := range texts {
+		if hit[i] {
+			continue
+		}
+		vec := embedded[missIdx[keys[i]]]
+		vecs[i] = slices.Clone(vec)
+		c.put(keys[i], vec)
+	}
+	return vecs, nil
+}
+
+// put stores vec under key, evicting the least recently used entry when the
+// cache is full. Caller must hold c.mu.
+func (c *CachedEmbedding) put(key [sha256.Size]byte, vec []float32) {
+	if el, ok := c.entries[key]; ok {
+		c.order.MoveToFront(el)
+		return
+	}
+	c.entries[key] = c.order.PushFront(&cacheEntry{key: key, vec: slices.Clone(vec)})
+	if c.order.Len() > c.size {
+		oldest := c.order.Back()
+		c.order.Remove(oldest)
+		delete(c.entries, oldest.Value.(*cacheEntry).key)
+	}
+}
+
+// compile-time check
+var _ core.EmbeddingProvider = (*CachedEmbedding)(nil)
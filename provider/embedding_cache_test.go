@@ -0,0 +1,105 @@
+package provider_test
+
+import (
+	"context"
+	"slices"
+	"testing"
+
+	"github.com/nevindra/oasis/provider"
+)
+
+// countingEmbedding returns a vector derived from each text's length and
+// records every text it was asked to embed.
+type countingEmbedding struct {
+	dims  int
+	calls int
+	texts []string
+}
+
+func (e *countingEmbedding) Name() string    { return "counting" }
+func (e *countingEmbedding) Dimensions() int { return e.dims }
+func (e *countingEmbedding) Embed(_ context.Context, texts []string) ([][]float32, error) {
+	e.calls++
+	e.texts = append(e.texts, texts...)
+	vecs := make([][]float32, len(texts))
+	for i, t := range texts {
+		vecs[i] = []float32{float32(len(t)), float32(e.dims)}
+	}
+	return vecs, nil
+}
+
+func TestCachedEmbedding_EmbedsIdenticalTextOnce(t *testing.T) {
+	inner := &countingEmbedding{dims: 2}
+	c := provider.NewCachedEmbedding(inner, 10)
+	ctx := context.Background()
+
+	first, err := c.Embed(ctx, []string{"hello", "hello"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	second, err := c.Embed(ctx, []string{"hello"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if inner.calls != 1 || !slices.Equal(inner.texts, []string{"hello"}) {
+		t.Fatalf("provider called %d times with %q, want once with [hello]", inner.calls, inner.texts)
+	}
+	if !slices.Equal(first[0], second[0]) || !slices.Equal(first[1], second[0]) {
+		t.Errorf("cached vectors differ: %v %v", first, second)
+	}
+
+	// Returned vectors are copies; mutating one must not poison the cache.
+	second[0][0] = -1
+	third, err := c.Embed(ctx, []string{"hello"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if third[0][0] != 5 {
+		t.Errorf("cache entry was mutated through a returned vector: %v", third[0])
+	}
+}
+
+func TestCachedEmbedding_OnlyMissesReachProvider(t *testing.T) {
+	inner := &countingEmbedding{dims: 2}
+	c := provider.NewCachedEmbedding(inner, 10)
+	ctx := context.Background()
+
+	if _, err := c.Embed(ctx, []string{"a", "bb"}); err != nil {
+		t.Fatal(err)
+	}
+	vecs, err := c.Embed(ctx, []string{"bb", "ccc", "a"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !slices.Equal(inner.texts, []string{"a", "bb", "ccc"}) {
+		t.Errorf("provider saw %q, want [a bb ccc]", inner.texts)
+	}
+	for i, want := range []float32{2, 3, 1} {
+		if vecs[i][0] != want {
+			t.Errorf("vecs[%d] = %v, want length %v", i, vecs[i], want)
+		}
+	}
+}
+
+func TestCachedEmbedding_EvictsLeastRecentlyUsed(t *testing.T) {
+	inner := &countingEmbedding{dims: 2}
+	c := provider.NewCachedEmbedding(inner, 2)
+	ctx := context.Background()
+
+	for _, text := range []string{"a", "b", "a", "c"} { // c evicts b, the least recent
+		if _, err := c.Embed(ctx, []string{text}); err != nil {
+			t.Fatal(err)
+		}
+	}
+	inner.texts = nil
+
+	if _, err := c.Embed(ctx, []string{"a", "b"}); err != nil {
+		t.Fatal(err)
+	}
+	if !slices.Equal(inner.texts, []string{"b"}) {
+		t.Errorf("provider saw %q, want [b] re-embedded after eviction", inner.texts)
+	}
+	if c.Len() != 2 {
+		t.Errorf("Len = %d, want 2", c.Len())
+	}
+}
@@ -370,13 +370,17 @@ type GeminiEmbedding struct {
 }
 
 // NewEmbedding creates a new Gemini embedding provider.
-func NewEmbedding(apiKey, model string, dims int) *GeminiEmbedding {
-	return &GeminiEmbedding{
+func NewEmbedding(apiKey, model string, dims int, opts ...EmbeddingOption) *GeminiEmbedding {
+	e := &GeminiEmbedding{
 		apiKey:     apiKey,
 		model:      model,
 		dims:       dims,
 		httpClient: &http.Client{},
 	}
+	for _, opt := range opts {
+		opt(e)
+	}
+	return e
 }
 
 // Name returns "gemini".
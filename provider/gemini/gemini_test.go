@@ -866,6 +866,17 @@ func TestNewWithOptions(t *testing.T) {
 	}
 }
 
+func TestWithHTTPClient(t *testing.T) {
+	c := &http.Client{Timeout: 5 * time.Second}
+
+	if g := New("key", "model", WithHTTPClient(c)); g.httpClient != c {
+		t.Error("WithHTTPClient did not replace the chat client")
+	}
+	if e := NewEmbedding("key", "model", 768, WithEmbeddingHTTPClient(c)); e.httpClient != c {
+		t.Error("WithEmbeddingHTTPClient did not replace the embedding client")
+	}
+}
+
 func TestExtractAttachmentsFromParsed(t *testing.T) {
 	raw := `{
 		"candidates": [{
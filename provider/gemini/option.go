@@ -1,6 +1,9 @@
 package gemini
 
-import "log/slog"
+import (
+	"log/slog"
+	"net/http"
+)
 
 // Option configures a Gemini provider.
 type Option func(*Gemini)
@@ -80,3 +83,18 @@ func WithCachedContent(name string) Option {
 func WithLogger(l *slog.Logger) Option {
 	return func(g *Gemini) { g.logger = l }
 }
+
+// WithHTTPClient sets a custom HTTP client (e.g. for timeouts or proxies).
+// The client is shared by chat, batch, and cache requests.
+func WithHTTPClient(c *http.Client) Option {
+	return func(g *Gemini) { g.httpClient = c }
+}
+
+// EmbeddingOption configures a GeminiEmbedding provider.
+type EmbeddingOption func(*GeminiEmbedding)
+
+// WithEmbeddingHTTPClient sets a custom HTTP client (e.g. for timeouts or
+// proxies) for embedding and batch-embedding requests.
+func WithEmbeddingHTTPClient(c *http.Client) EmbeddingOption {
+	return func(e *GeminiEmbedding) { e.httpClient = c }
+}
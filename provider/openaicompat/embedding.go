@@ -8,6 +8,7 @@ import (
 	"fmt"
 	"io"
 	"net/http"
+	"sync"
 
 	oasis "github.com/nevindra/oasis/core"
 )
@@ -73,15 +74,33 @@ func WithEmbeddingHTTPClient(c *http.Client) EmbeddingOption {
 	return func(e *Embedding) { e.client = c }
 }
 
+// WithEmbeddingBatchSize caps the number of texts sent per request. Longer
+// inputs to Embed are split into consecutive batches and the vectors are
+// returned in input order. Zero (the default) sends all texts in one request.
+// Set it to the provider's per-request input limit (e.g. 2048 for OpenAI, far
+// less for some self-hosted servers).
+func WithEmbeddingBatchSize(n int) EmbeddingOption {
+	return func(e *Embedding) { e.batchSize = n }
+}
+
+// WithEmbeddingConcurrency sets how many batches may be in flight at once
+// (default 1, sequential). Only applies when WithEmbeddingBatchSize splits
+// the input.
+func WithEmbeddingConcurrency(n int) EmbeddingOption {
+	return func(e *Embedding) { e.concurrency = n }
+}
+
 // Embedding implements oasis.EmbeddingProvider for any OpenAI-compatible
 // embedding API (OpenAI, vLLM, Ollama, etc.).
 type Embedding struct {
-	apiKey  string
-	model   string
-	baseURL string
-	dims    int
-	client  *http.Client
-	name    string
+	apiKey      string
+	model       string
+	baseURL     string
+	dims        int
+	client      *http.Client
+	name        string
+	batchSize   int // 0 = no cap
+	concurrency int
 }
 
 // NewEmbedding creates an OpenAI-compatible embedding provider.
@@ -109,8 +128,66 @@ func (e *Embedding) Name() string { return e.name }
 // Dimensions returns the configured embedding dimensionality.
 func (e *Embedding) Dimensions() int { return e.dims }
 
-// Embed returns embedding vectors for the given texts.
+// Embed returns embedding vectors for the given texts, one per text in input
+// order. With WithEmbeddingBatchSize, texts are sent in batches; the first
+// failing batch cancels the rest and its error is returned.
 func (e *Embedding) Embed(ctx context.Context, texts []string) ([][]float32, error) {
+	if e.batchSize <= 0 || len(texts) <= e.batchSize {
+		return e.embedTexts(ctx, texts)
+	}
+
+	ctx, cancel := context.WithCancel(ctx)
+	defer cancel()
+	vecs := make([][]float32, len(texts))
+	sem := make(chan struct{}, max(e.concurrency, 1))
+	var (
+		wg       sync.WaitGroup
+		errOnce  sync.Once
+		firstErr error
+	)
+	fail := func(err error) {
+		errOnce.Do(func() {
+			firstErr = err
+			cancel()
+		})
+	}
+
+	for start := 0; start < len(texts) && ctx.Err() == nil; start += e.batchSize {
+		end := min(start+e.batchSize, len(texts))
+		select {
+		case sem <- struct{}{}:
+		case <-ctx.Done():
+			continue // loop condition exits
+		}
+		wg.Add(1)
+		go func(start, end int) {
+			defer wg.Done()
+			defer func() { <-sem }()
+			batch, err := e.embedTexts(ctx, texts[start:end])
+			if err != nil {
+				fail(err)
+				return
+			}
+			if len(batch) != end-start {
+				fail(&oasis.ErrLLM{Provider: e.name, Message: fmt.Sprintf("embed batch returned %d vectors for %d texts", len(batch), end-start)})
+				return
+			}
+			copy(vecs[start:end], batch)
+		}(start, end)
+	}
+	wg.Wait()
+
+	if firstErr != nil {
+		return nil, firstErr
+	}
+	if err := ctx.Err(); err != nil {
+		return nil, err
+	}
+	return vecs, nil
+}
+
+// embedTexts embeds texts in a single request.
+func (e *Embedding) embedTexts(ctx context.Context, texts []string) ([][]float32, error) {
 	req := embedRequest{
 		Model: e.model,
 		Input: textInput(texts),
@@ -6,6 +6,8 @@ import (
 	"io"
 	"net/http"
 	"net/http/httptest"
+	"strconv"
+	"sync"
 	"testing"
 
 	oasis "github.com/nevindra/oasis/core"
@@ -64,6 +66,69 @@ func TestEmbedding_Embed(t *testing.T) {
 	}
 }
 
+func TestEmbedding_EmbedBatched(t *testing.T) {
+	var mu sync.Mutex
+	var sizes []int
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		var req struct {
+			Input []string `json:"input"`
+		}
+		if err := json.NewDecoder(r.Body).Decode(&req); err != nil {
+			t.Errorf("decode: %v", err)
+			return
+		}
+		mu.Lock()
+		sizes = append(sizes, len(req.Input))
+		mu.Unlock()
+		// Each vector encodes its text's numeric value so order is checkable.
+		resp := EmbedResponse{}
+		for i, text := range req.Input {
+			v, _ := strconv.Atoi(text)
+			resp.Data = append(resp.Data, EmbedData{Index: i, Embedding: []float32{float32(v)}})
+		}
+		json.NewEncoder(w).Encode(resp)
+	}))
+	defer srv.Close()
+
+	e := NewEmbedding("key", "model", srv.URL, 1,
+		WithEmbeddingBatchSize(2), WithEmbeddingConcurrency(2))
+	texts := []string{"0", "1", "2", "3", "4"}
+	vecs, err := e.Embed(context.Background(), texts)
+	if err != nil {
+		t.Fatalf("Embed: %v", err)
+	}
+	if len(vecs) != len(texts) {
+		t.Fatalf("expected %d vectors, got %d", len(texts), len(vecs))
+	}
+	for i, v := range vecs {
+		if len(v) != 1 || v[0] != float32(i) {
+			t.Errorf("vecs[%d] = %v, want [%d]", i, v, i)
+		}
+	}
+	if len(sizes) != 3 {
+		t.Errorf("expected 3 requests, got %d (%v)", len(sizes), sizes)
+	}
+	for _, n := range sizes {
+		if n > 2 {
+			t.Errorf("request carried %d texts, batch size is 2", n)
+		}
+	}
+}
+
+func TestEmbedding_EmbedBatchedError(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusServiceUnavailable)
+	}))
+	defer srv.Close()
+
+	e := NewEmbedding("key", "model", srv.URL, 1, WithEmbeddingBatchSize(1))
+	_, err := e.Embed(context.Background(), []string{"a", "b", "c"})
+	httpErr, ok := err.(*oasis.ErrHTTP)
+	if !ok || httpErr.Status != http.StatusServiceUnavailable {
+		t.Fatalf("expected *ErrHTTP 503, got %v", err)
+	}
+}
+
 func TestEmbedding_Dimensions(t *testing.T) {
 	e := NewEmbedding("key", "model", "http://localhost", 768)
 	if e.Dimensions() != 768 {
@@ -0,0 +1,105 @@
+package provider
+
+import (
+	"context"
+	"errors"
+	"sync/atomic"
+
+	"github.com/nevindra/oasis/core"
+)
+
+// Pool spreads calls across providers that differ only in their
+// credentials — typically one per API key — so per-key rate limits add up.
+// Calls are assigned round-robin. A call rejected with HTTP 429 is retried
+// immediately on the next provider, trying each at most once, before the
+// 429 is returned; wrap the pool in agent.RetryMiddleware to back off once
+// every key is limited:
+//
+//	p := provider.Chain(agent.RetryMiddleware())(provider.Pool(
+//	    openaicompat.NewProvider(keyA, model, baseURL),
+//	    openaicompat.NewProvider(keyB, model, baseURL),
+//	))
+//
+// A streaming call fails over only when the rate-limited attempt emitted no
+// events. Name reports the first provider's name. Panics if providers is
+// empty.
+func Pool(providers ...core.Provider) core.Provider {
+	if len(providers) == 0 {
+		panic("provider.Pool: at least one provider is required")
+	}
+	return &pool{providers: providers}
+}
+
+type pool struct {
+	providers []core.Provider
+	next      atomic.Uint64
+}
+
+// Name returns the first provider's name.
+func (p *pool) Name() string { return p.providers[0].Name() }
+
+// ChatStream implements core.Provider. ch is always closed before returning.
+func (p *pool) ChatStream(ctx context.Context, req core.ChatRequest, ch chan<- core.StreamEvent) (core.ChatResponse, error) {
+	n := uint64(len(p.providers))
+	start := (p.next.Add(1) - 1) % n
+	var lastErr error
+	for i := range n {
+		inner := p.providers[(start+i)%n]
+		if ch == nil {
+			resp, err := inner.ChatStream(ctx, req, nil)
+			if !isRateLimited(err) {
+				return resp, err
+			}
+			lastErr = err
+			continue
+		}
+		resp, sent, err := ForwardStream(ctx, inner, req, ch)
+		if sent || !isRateLimited(err) {
+			close(ch)
+			return resp, err
+		}
+		lastErr = err
+	}
+	if ch != nil {
+		close(ch)
+	}
+	return core.ChatResponse{}, lastErr
+}
+
+// ForwardStream runs one streaming call to inner through an intermediate
+// channel, copying its events to ch without closing it, and reports whether
+// any event reached ch. Middleware that may re-issue a call (failover,
+// retries) uses it to keep ch open across attempts and to tell whether an
+// attempt is still safe to repeat: once an event was sent, it is not.
+func ForwardStream(ctx context.Context, inner core.Provider, req core.ChatRequest, ch chan<- core.StreamEvent) (core.ChatResponse, bool, error) {
+	mid := make(chan core.StreamEvent, 1)
+	var (
+		resp core.ChatResponse
+		err  error
+	)
+	done := make(chan struct{})
+	go func() {
+		defer close(done)
+		resp, err = inner.ChatStream(ctx, req, mid)
+	}()
+	sent := false
+	for ev := range mid {
+		sent = true
+		select {
+		case ch <- ev:
+		case <-ctx.Done():
+			// Keep draining so the inner provider can return.
+		}
+	}
+	<-done
+	return resp, sent, err
+}
+
+// isRateLimited reports whether err is an HTTP 429.
+func isRateLimited(err error) bool {
+	var e *core.ErrHTTP
+	return errors.As(err, &e) && e.Status == 429
+}
+
+// compile-time check
+var _ core.Provider = (*pool)(nil)
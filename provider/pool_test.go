@@ -0,0 +1,96 @@
+package provider_test
+
+import (
+	"context"
+	"errors"
+	"slices"
+	"testing"
+
+	"github.com/nevindra/oasis/core"
+	"github.com/nevindra/oasis/provider"
+)
+
+// keyProvider answers with its key as content, or fails with err.
+type keyProvider struct {
+	key   string
+	err   error
+	calls int
+}
+
+func (k *keyProvider) Name() string { return "keyed" }
+func (k *keyProvider) ChatStream(_ context.Context, _ core.ChatRequest, ch chan<- core.StreamEvent) (core.ChatResponse, error) {
+	if ch != nil {
+		defer close(ch)
+	}
+	k.calls++
+	if k.err != nil {
+		return core.ChatResponse{}, k.err
+	}
+	if ch != nil {
+		ch <- core.StreamEvent{Type: core.EventTextDelta, Content: k.key}
+	}
+	return core.ChatResponse{Content: k.key}, nil
+}
+
+func TestPool_RoundRobin(t *testing.T) {
+	a, b := &keyProvider{key: "A"}, &keyProvider{key: "B"}
+	p := provider.Pool(a, b)
+	var got []string
+	for range 4 {
+		resp, err := core.Chat(context.Background(), p, core.ChatRequest{})
+		if err != nil {
+			t.Fatal(err)
+		}
+		got = append(got, resp.Content)
+	}
+	if want := []string{"A", "B", "A", "B"}; !slices.Equal(got, want) {
+		t.Errorf("got %v, want %v", got, want)
+	}
+}
+
+func TestPool_FailsOverOn429(t *testing.T) {
+	a := &keyProvider{key: "A", err: &core.ErrHTTP{Status: 429}}
+	b := &keyProvider{key: "B"}
+	p := provider.Pool(a, b)
+
+	resp, err := core.Chat(context.Background(), p, core.ChatRequest{})
+	if err != nil || resp.Content != "B" {
+		t.Fatalf("got %q, %v; want B from the second key", resp.Content, err)
+	}
+
+	ch := make(chan core.StreamEvent, 4)
+	if _, err := p.ChatStream(context.Background(), core.ChatRequest{}, ch); err != nil {
+		t.Fatal(err)
+	}
+	var streamed string
+	for ev := range ch {
+		streamed += ev.Content
+	}
+	if streamed != "B" {
+		t.Errorf("streamed %q, want B", streamed)
+	}
+}
+
+func TestPool_AllKeysLimited(t *testing.T) {
+	limited := &core.ErrHTTP{Status: 429}
+	a, b := &keyProvider{err: limited}, &keyProvider{err: limited}
+	_, err := core.Chat(context.Background(), provider.Pool(a, b), core.ChatRequest{})
+	var httpErr *core.ErrHTTP
+	if !errors.As(err, &httpErr) || httpErr.Status != 429 {
+		t.Fatalf("err = %v, want the 429", err)
+	}
+	if a.calls != 1 || b.calls != 1 {
+		t.Errorf("calls = %d, %d; want each key tried once", a.calls, b.calls)
+	}
+}
+
+func TestPool_DoesNotFailOverOtherErrors(t *testing.T) {
+	a := &keyProvider{err: &core.ErrHTTP{Status: 400}}
+	b := &keyProvider{key: "B"}
+	if _, err := core.Chat(context.Background(), provider.Pool(a, b), core.ChatRequest{}); err == nil {
+		t.Fatal("expected the 400 to be returned")
+	}
+	if b.calls != 0 {
+		t.Error("a bad request must not be retried on another key")
+	}
+}
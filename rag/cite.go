@@ -0,0 +1,112 @@
+package rag
+
+import (
+	"regexp"
+	"strconv"
+	"strings"
+
+	"github.com/nevindra/oasis/core"
+)
+
+// citationMarker matches inline [n] citation markers.
+var citationMarker = regexp.MustCompile(`\[(\d+)\]`)
+
+// CiteSources rewrites the [n] citation markers in answer into numbered
+// markdown links and returns the sources actually cited.
+//
+// Markers refer to 1-based positions in sources — the numbering shown to the
+// model when the retrieved context was assembled. In the output, sources are
+// renumbered by first citation, de-duplicated by URL (by Title when URL is
+// empty, so several chunks of one document collapse into one entry), and each
+// marker becomes [k](url), or stays [k] for a source with no URL. Markers
+// that are out of range or already followed by "(" (an existing link) are
+// left untouched. Uncited sources are dropped.
+func CiteSources(answer string, sources []core.Source) (string, []core.Source) {
+	matches := citationMarker.FindAllStringSubmatchIndex(answer, -1)
+	if len(matches) == 0 {
+		return answer, nil
+	}
+
+	var (
+		sb      strings.Builder
+		cited   []core.Source
+		numbers = make(map[string]int) // dedup key → output number
+		last    int
+	)
+	sb.Grow(len(answer))
+	for _, m := range matches {
+		start, end := m[0], m[1]
+		if end < len(answer) && answer[end] == '(' {
+			continue // already a markdown link
+		}
+		n, err := strconv.Atoi(answer[m[2]:m[3]])
+		if err != nil || n < 1 || n > len(sources) {
+			continue
+		}
+		src := sources[n-1]
+		key := src.URL
+		if key == "" {
+			key = src.Title
+		}
+		num, ok := numbers[key]
+		if !ok || key == "" {
+			cited = append(cited, src)
+			num = len(cited)
+			if key != "" {
+				numbers[key] = num
+			}
+		}
+
+		sb.WriteString(answer[last:start])
+		sb.WriteByte('[')
+		sb.WriteString(strconv.Itoa(num))
+		sb.WriteByte(']')
+		if src.URL != "" {
+			sb.WriteByte('(')
+			sb.WriteString(src.URL)
+			sb.WriteByte(')')
+		}
+		last = end
+	}
+	sb.WriteString(answer[last:])
+	return sb.String(), cited
+}
+
+// FormatSources renders sources as a numbered reference list to append to
+// an answer, typically the cited sources returned by CiteSources:
+//
+//	Sources:
+//	[1] Refund policy (https://example.com/refunds) — "Refunds are issued within 14 days."
+//
+// Each entry shows the Title, the URL in parentheses (or the URL alone when
+// there is no title), and the Quote with whitespace collapsed and cut to
+// quoteLen runes. quoteLen <= 0 omits quotes. Returns "" for no sources.
+func FormatSources(sources []core.Source, quoteLen int) string {
+	if len(sources) == 0 {
+		return ""
+	}
+	var sb strings.Builder
+	sb.WriteString("Sources:")
+	for i, src := range sources {
+		sb.WriteString("\n[")
+		sb.WriteString(strconv.Itoa(i + 1))
+		sb.WriteString("] ")
+		switch {
+		case src.Title != "" && src.URL != "":
+			sb.WriteString(src.Title + " (" + src.URL + ")")
+		case src.Title != "":
+			sb.WriteString(src.Title)
+		default:
+			sb.WriteString(src.URL)
+		}
+		quote := strings.Join(strings.Fields(src.Quote), " ")
+		if quoteLen <= 0 || quote == "" {
+			continue
+		}
+		if r := []rune(quote); len(r) > quoteLen {
+			quote = strings.TrimSpace(string(r[:quoteLen])) + "…"
+		}
+		sb.WriteString(` — "` + quote + `"`)
+	}
+	return sb.String()
+}
@@ -0,0 +1,88 @@
+package rag
+
+import (
+	"testing"
+
+	"github.com/nevindra/oasis/core"
+)
+
+func TestCiteSources(t *testing.T) {
+	sources := []core.Source{
+		{URL: "https://a.example/doc", Title: "A"},
+		{URL: "https://b.example/post", Title: "B"},
+		{URL: "https://a.example/doc", Title: "A (chunk 2)"},
+		{Title: "Internal memo"},
+	}
+	tests := []struct {
+		name      string
+		answer    string
+		want      string
+		wantTitle []string
+	}{
+		{
+			name:      "renumbered by first citation",
+			answer:    "Rates rose [2] after inflation [1].",
+			want:      "Rates rose [1](https://b.example/post) after inflation [2](https://a.example/doc).",
+			wantTitle: []string{"B", "A"},
+		},
+		{
+			name:      "chunks of one URL collapse",
+			answer:    "See [1] and [3].",
+			want:      "See [1](https://a.example/doc) and [1](https://a.example/doc).",
+			wantTitle: []string{"A"},
+		},
+		{
+			name:      "source without URL stays a bare marker",
+			answer:    "Per the memo [4].",
+			want:      "Per the memo [1].",
+			wantTitle: []string{"Internal memo"},
+		},
+		{
+			name:   "out of range and existing links untouched",
+			answer: "Odd [9] and [1](https://x.example).",
+			want:   "Odd [9] and [1](https://x.example).",
+		},
+		{
+			name:   "no markers",
+			answer: "Plain answer.",
+			want:   "Plain answer.",
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, cited := CiteSources(tt.answer, sources)
+			if got != tt.want {
+				t.Errorf("answer = %q, want %q", got, tt.want)
+			}
+			if len(cited) != len(tt.wantTitle) {
+				t.Fatalf("cited %d sources, want %d (%+v)", len(cited), len(tt.wantTitle), cited)
+			}
+			for i, title := range tt.wantTitle {
+				if cited[i].Title != title {
+					t.Errorf("cited[%d].Title = %q, want %q", i, cited[i].Title, title)
+				}
+			}
+		})
+	}
+}
+
+func TestFormatSources(t *testing.T) {
+	sources := []core.Source{
+		{URL: "https://a.example/refunds", Title: "Refund policy", Quote: "Refunds are issued\n within 14 days."},
+		{Title: "Internal memo", Quote: "The rollout starts on Monday for all regions and continues weekly."},
+		{URL: "https://b.example/post"},
+	}
+	want := "Sources:\n" +
+		`[1] Refund policy (https://a.example/refunds) — "Refunds are issued within 14 days."` + "\n" +
+		`[2] Internal memo — "The rollout starts on Monday for all reg…"` + "\n" +
+		"[3] https://b.example/post"
+	if got := FormatSources(sources, 40); got != want {
+		t.Errorf("FormatSources =\n%s\nwant\n%s", got, want)
+	}
+	if got := FormatSources(sources[:1], 0); got != "Sources:\n[1] Refund policy (https://a.example/refunds)" {
+		t.Errorf("quoteLen 0 = %q", got)
+	}
+	if got := FormatSources(nil, 50); got != "" {
+		t.Errorf("no sources = %q, want empty", got)
+	}
+}
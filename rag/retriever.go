@@ -3,6 +3,7 @@ package rag
 import (
 	"context"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"log/slog"
 	"sort"
@@ -54,9 +55,11 @@ type RetrieverOption func(*retrieverConfig)
 type retrieverConfig struct {
 	reranker            Reranker
 	minScore            float32
+	minVectorScore      float32
 	keywordWeight       float32
 	overfetchMultiplier int
 	filters             []core.ChunkFilter
+	keywordFallback     bool
 	tracer              core.Tracer
 	logger              *slog.Logger
 }
@@ -72,6 +75,17 @@ func WithMinRetrievalScore(score float32) RetrieverOption {
 	return func(c *retrieverConfig) { c.minScore = score }
 }
 
+// WithMinVectorScore drops vector search candidates whose raw similarity
+// score (as returned by Store.SearchChunks) is below score, before fusion.
+// Unlike WithMinRetrievalScore, which applies to rank-based fused scores —
+// the best vector hit scores high however poor the match — this filters on
+// absolute relevance, so a query with nothing related in the store returns
+// no results instead of its nearest neighbours. Keyword matches are not
+// affected. Default is 0 (no filtering).
+func WithMinVectorScore(score float32) RetrieverOption {
+	return func(c *retrieverConfig) { c.minVectorScore = score }
+}
+
 // WithKeywordWeight sets the relative weight for keyword search results in
 // the RRF merge. Must be in [0, 1]. Default is 0.3 (vector gets 0.7).
 func WithKeywordWeight(w float32) RetrieverOption {
@@ -90,6 +104,15 @@ func WithFilters(filters ...core.ChunkFilter) RetrieverOption {
 	return func(c *retrieverConfig) { c.filters = filters }
 }
 
+// WithKeywordFallback keeps retrieval working while the embedding provider
+// is down: when embedding the query fails, Retrieve answers from keyword
+// search alone instead of returning the error, and logs a warning. Requires
+// a store implementing core.KeywordSearcher; other stores still return the
+// embedding error. A failed keyword search is then returned as the error.
+func WithKeywordFallback() RetrieverOption {
+	return func(c *retrieverConfig) { c.keywordFallback = true }
+}
+
 // WithRetrieverTracer sets the core.Tracer for a HybridRetriever.
 func WithRetrieverTracer(t core.Tracer) RetrieverOption {
 	return func(c *retrieverConfig) { c.tracer = t }
@@ -268,12 +291,18 @@ func (h *HybridRetriever) Sources() []core.Source {
 
 func (h *HybridRetriever) retrieveInner(ctx context.Context, query string, topK int) ([]RetrievalResult, error) {
 	embs, err := h.embedding.Embed(ctx, []string{query})
+	if err == nil && len(embs) == 0 {
+		err = errors.New("no embedding returned")
+	}
 	if err != nil {
+		if _, ok := h.store.(core.KeywordSearcher); ok && h.cfg.keywordFallback && ctx.Err() == nil {
+			if h.cfg.logger != nil {
+				h.cfg.logger.Warn("embed query failed, falling back to keyword-only search", "err", err)
+			}
+			return h.retrieveWithEmbedding(ctx, nil, query, topK)
+		}
 		return nil, fmt.Errorf("embed query: %w", err)
 	}
-	if len(embs) == 0 {
-		return nil, fmt.Errorf("embed query: no embedding returned")
-	}
 	return h.retrieveWithEmbedding(ctx, embs[0], query, topK)
 }
 
@@ -309,7 +338,16 @@ func (h *HybridRetriever) retrieveWithEmbedding(ctx context.Context, queryEmbedd
 	)
 	ks, hasKeyword := h.store.(core.KeywordSearcher)
 
-	if hasKeyword {
+	// A nil embedding is the WithKeywordFallback path: keyword search is
+	// the only source, so its failure is the call's failure.
+	keywordOnly := queryEmbedding == nil && hasKeyword
+	if keywordOnly {
+		var kwErr error
+		keywordResults, kwErr = ks.SearchChunksKeyword(ctx, query, fetchK, h.cfg.filters...)
+		if kwErr != nil {
+			return nil, fmt.Errorf("keyword search: %w", kwErr)
+		}
+	} else if hasKeyword {
 		var wg sync.WaitGroup
 		wg.Add(2)
 		go func() {
@@ -332,8 +370,24 @@ func (h *HybridRetriever) retrieveWithEmbedding(ctx context.Context, queryEmbedd
 		return nil, fmt.Errorf("vector search: %w", vectorErr)
 	}
 
+	if h.cfg.minVectorScore > 0 {
+		kept := make([]core.ScoredChunk, 0, len(vectorResults)) // don't write into the store's slice
+		for _, sc := range vectorResults {
+			if sc.Score >= h.cfg.minVectorScore {
+				kept = append(kept, sc)
+			}
+		}
+		if dropped := len(vectorResults) - len(kept); dropped > 0 && h.cfg.logger != nil {
+			h.cfg.logger.Debug("dropped weak vector matches",
+				"dropped", dropped, "min_vector_score", h.cfg.minVectorScore)
+		}
+		vectorResults = kept
+	}
+
 	var results []RetrievalResult
-	if len(keywordResults) > 0 {
+	if keywordOnly {
+		results = reciprocalRankFusion(nil, keywordResults, 1)
+	} else if len(keywordResults) > 0 {
 		results = reciprocalRankFusion(vectorResults, keywordResults, h.cfg.keywordWeight)
 	} else {
 		results = reciprocalRankFusion(vectorResults, nil, 0)
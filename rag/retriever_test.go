@@ -2,6 +2,7 @@ package rag
 
 import (
 	"context"
+	"errors"
 	"testing"
 
 	"github.com/nevindra/oasis/core"
@@ -396,6 +397,44 @@ func TestHybridRetriever_WithReranker(t *testing.T) {
 	}
 }
 
+func TestHybridRetriever_MinVectorScore(t *testing.T) {
+	store := &retrieverStore{
+		chunks: []core.ScoredChunk{
+			{Chunk: core.Chunk{ID: "c1", Content: "loosely related"}, Score: 0.31},
+			{Chunk: core.Chunk{ID: "c2", Content: "unrelated"}, Score: 0.12},
+		},
+	}
+	emb := &mockEmbeddingProvider{embedding: []float32{0.1}}
+
+	// Fused scores are rank-based, so the rank threshold alone keeps noise.
+	noisy, err := NewHybridRetriever(store, emb, WithMinRetrievalScore(0.5)).Retrieve(context.Background(), "q", 5)
+	if err != nil {
+		t.Fatalf("Retrieve() error = %v", err)
+	}
+	if len(noisy) == 0 {
+		t.Fatal("expected WithMinRetrievalScore to keep the top rank-fused hit")
+	}
+
+	results, err := NewHybridRetriever(store, emb, WithMinVectorScore(0.5)).Retrieve(context.Background(), "q", 5)
+	if err != nil {
+		t.Fatalf("Retrieve() error = %v", err)
+	}
+	if len(results) != 0 {
+		t.Errorf("len = %d, want 0 for a low-relevance query", len(results))
+	}
+
+	results, err = NewHybridRetriever(store, emb, WithMinVectorScore(0.3)).Retrieve(context.Background(), "q", 5)
+	if err != nil {
+		t.Fatalf("Retrieve() error = %v", err)
+	}
+	if len(results) != 1 || results[0].ChunkID != "c1" {
+		t.Errorf("results = %+v, want only c1", results)
+	}
+	if len(store.chunks) != 2 || store.chunks[1].ID != "c2" {
+		t.Error("store's result slice was modified")
+	}
+}
+
 func TestHybridRetriever_HybridSearch(t *testing.T) {
 	store := &retrieverStore{
 		chunks: []core.ScoredChunk{
@@ -421,6 +460,33 @@ func TestHybridRetriever_HybridSearch(t *testing.T) {
 	}
 }
 
+func TestHybridRetriever_KeywordFallback(t *testing.T) {
+	store := &retrieverStore{
+		chunks: []core.ScoredChunk{
+			{Chunk: core.Chunk{ID: "c1", Content: "vector match"}, Score: 0.9},
+		},
+		keywords: []core.ScoredChunk{
+			{Chunk: core.Chunk{ID: "c2", Content: "keyword match"}, Score: 0.7},
+		},
+	}
+	down := &mockEmbeddingProvider{err: errors.New("embedding service unavailable")}
+
+	if _, err := NewHybridRetriever(store, down).Retrieve(context.Background(), "test", 5); err == nil {
+		t.Fatal("without the fallback an embedding failure must be returned")
+	}
+
+	results, err := NewHybridRetriever(store, down, WithKeywordFallback()).Retrieve(context.Background(), "test", 5)
+	if err != nil {
+		t.Fatalf("Retrieve() error = %v", err)
+	}
+	if len(results) != 1 || results[0].ChunkID != "c2" {
+		t.Fatalf("results = %+v, want only the keyword match", results)
+	}
+	if results[0].Score < 0.99 {
+		t.Errorf("top keyword-only score = %v, want ~1 (normalized)", results[0].Score)
+	}
+}
+
 // --- LLMReranker tests ---
 
 func TestLLMReranker(t *testing.T) {
@@ -199,10 +199,14 @@ func flushOne(ctx context.Context, sb Sandbox, spec MountSpec, manifest *Manifes
 }
 
 func stripMountPrefix(mountPath, fullPath string) (string, bool) {
+	mountPath, fullPath = path.Clean(mountPath), path.Clean(fullPath)
 	if !strings.HasPrefix(fullPath, mountPath) {
 		return "", false
 	}
 	rel := strings.TrimPrefix(fullPath, mountPath)
+	if mountPath != "/" && rel != "" && !strings.HasPrefix(rel, "/") {
+		return "", false // "/workspace/out2" is not under "/workspace/out"
+	}
 	rel = strings.TrimPrefix(rel, "/")
 	if rel == "" {
 		return "", false
@@ -314,7 +314,7 @@ type TreeResult struct {
 type HTTPFetchRequest struct {
 	URL      string // required
 	Raw      bool   // true = raw HTML, false = readability extraction
-	MaxChars int    // 0 uses default (8000)
+	MaxChars int    // 0 uses the sandbox's default; the http_fetch tool always sets it
 }
 
 // HTTPFetchResult is the output of HTTPFetch.
@@ -7,8 +7,12 @@ import (
 	"fmt"
 	"io"
 	"mime"
+	"net/url"
+	"path"
 	"path/filepath"
+	"slices"
 	"strings"
+	"unicode"
 
 	"github.com/nevindra/oasis/core"
 	oasis "github.com/nevindra/oasis/core"
@@ -41,11 +45,17 @@ func (t toolImpl) ExecuteRaw(ctx context.Context, args json.RawMessage) (oasis.T
 // ToolsOption configures optional sandbox tool capabilities.
 type ToolsOption func(*toolsConfig)
 
+// defaultHTTPFetchMaxChars is http_fetch's default character cap. It matches
+// tools/http.DefaultMaxChars so both fetch tools return the same amount of
+// text by default; it is kept here so sandbox does not depend on tools/http.
+const defaultHTTPFetchMaxChars = 8000
+
 type toolsConfig struct {
-	delivery  FileDelivery
-	mounts    []MountSpec
-	manifest  *Manifest
-	noBrowser bool
+	delivery      FileDelivery
+	mounts        []MountSpec
+	manifest      *Manifest
+	noBrowser     bool
+	fetchMaxChars int
 }
 
 // WithFileDelivery enables the deliver_file tool with a single legacy
@@ -66,6 +76,13 @@ func WithoutBrowser() ToolsOption {
 	return func(c *toolsConfig) { c.noBrowser = true }
 }
 
+// WithHTTPFetchMaxChars sets the cap on characters returned by http_fetch
+// (default 8000, the same as tools/http). The model's
+// max_chars argument can lower it per call but never raise it.
+func WithHTTPFetchMaxChars(n int) ToolsOption {
+	return func(c *toolsConfig) { c.fetchMaxChars = n }
+}
+
 // WithMounts attaches a slice of FilesystemMount specs to the tool layer.
 // Tool wrappers consult the mounts to publish writes back to the backend
 // and to look up version preconditions in the supplied manifest.
@@ -84,13 +101,24 @@ func WithMounts(specs []MountSpec, manifest *Manifest) ToolsOption {
 // match wins so that a nested mount takes precedence over a parent mount.
 // The second return value is the path's logical key relative to the
 // matched mount root.
+//
+// Both p and the mount paths are cleaned first, so "." and ".." segments
+// resolve the way the sandbox resolves them. Relative paths never match:
+// where they land depends on the sandbox's working directory.
 func findMountForPath(mounts []MountSpec, p string) (*MountSpec, string) {
+	// Why: matching the raw string attributed "/workspace/output/../inputs/x"
+	// to the output mount with key "../inputs/x", a backend key outside the
+	// mount root.
+	if !path.IsAbs(p) {
+		return nil, ""
+	}
+	p = path.Clean(p)
 	var best *MountSpec
 	bestLen := -1
 	var bestKey string
 	for i := range mounts {
 		m := &mounts[i]
-		prefix := m.Path
+		prefix := path.Clean(m.Path)
 		if !strings.HasPrefix(p, prefix) {
 			continue
 		}
@@ -199,10 +227,19 @@ type browserWaitArgs struct {
 	State     string `json:"state,omitempty" describe:"selector kind only: visible (default) or hidden"`
 }
 
+type browserFillFormArgs struct {
+	Fields []formFieldArg `json:"fields" describe:"Fields to fill, in form order"`
+}
+
+type formFieldArg struct {
+	Label string `json:"label" describe:"Visible label or placeholder of the field (e.g., 'Email', 'First name')"`
+	Value string `json:"value" describe:"Text to enter, or the option value to choose for a dropdown"`
+}
+
 type httpFetchArgs struct {
 	URL      string `json:"url" describe:"URL to fetch"`
 	Raw      bool   `json:"raw,omitempty" describe:"true = raw HTML, false = readability extraction (default)"`
-	MaxChars int    `json:"max_chars,omitempty" describe:"Truncation limit in characters (default: 8000)"`
+	MaxChars int    `json:"max_chars,omitempty" describe:"Truncation limit in characters (capped at the tool's limit)"`
 }
 
 type webSearchArgs struct {
@@ -223,7 +260,7 @@ type deliverFileArgs struct {
 
 // Tools returns Oasis tool implementations backed by the given Sandbox.
 func Tools(sb Sandbox, opts ...ToolsOption) []oasis.AnyTool {
-	cfg := &toolsConfig{}
+	cfg := &toolsConfig{fetchMaxChars: defaultHTTPFetchMaxChars}
 	for _, o := range opts {
 		o(cfg)
 	}
@@ -237,7 +274,7 @@ func Tools(sb Sandbox, opts ...ToolsOption) []oasis.AnyTool {
 		fileGlobTool(sb),
 		fileGrepTool(sb),
 		fileTreeTool(sb),
-		httpFetchTool(sb),
+		httpFetchTool(sb, cfg),
 		workspaceInfoTool(sb),
 		mcpCallTool(sb),
 		webSearchTool(sb),
@@ -257,6 +294,7 @@ func Tools(sb Sandbox, opts ...ToolsOption) []oasis.AnyTool {
 			browserEvalTool(b),
 			browserFindTool(b),
 			browserWaitTool(b),
+			browserFillFormTool(b),
 		)
 	}
 
@@ -513,7 +551,7 @@ func fileTreeTool(sb Sandbox) toolImpl {
 		})
 }
 
-func httpFetchTool(sb Sandbox) toolImpl {
+func httpFetchTool(sb Sandbox, cfg *toolsConfig) toolImpl {
 	return newTool("http_fetch",
 		"Fetch a URL and extract readable text content. Returns clean text by default with HTML noise removed. Use raw=true to get unprocessed HTML. NOTE: This is a simple HTTP GET — sites with bot protection (Cloudflare, WAF) will block it. If this tool returns 403/502 errors, use the browser tool to navigate to the URL instead, then use page_text to extract content.",
 		string(core.DeriveSchema[httpFetchArgs]()),
@@ -522,7 +560,11 @@ func httpFetchTool(sb Sandbox) toolImpl {
 			if err := json.Unmarshal(args, &p); err != nil {
 				return oasis.ToolResult{Error: "invalid args: " + err.Error()}, nil
 			}
-			res, err := sb.HTTPFetch(ctx, HTTPFetchRequest{URL: p.URL, Raw: p.Raw, MaxChars: p.MaxChars})
+			limit := cfg.fetchMaxChars
+			if p.MaxChars > 0 && (limit <= 0 || p.MaxChars < limit) {
+				limit = p.MaxChars
+			}
+			res, err := sb.HTTPFetch(ctx, HTTPFetchRequest{URL: p.URL, Raw: p.Raw, MaxChars: limit})
 			if err != nil {
 				errMsg := err.Error()
 				if strings.Contains(errMsg, "403") || strings.Contains(errMsg, "502") || strings.Contains(errMsg, "503") ||
@@ -635,15 +677,21 @@ func snapshotTool(sb BrowserSandbox) toolImpl {
 			if err != nil {
 				return oasis.ToolResult{Error: err.Error()}, nil
 			}
-			var out strings.Builder
-			fmt.Fprintf(&out, "url: %s\ntitle: %s\n", snap.URL, snap.Title)
-			for _, n := range snap.Nodes {
-				fmt.Fprintf(&out, "[%s] %s %q\n", n.Ref, n.Role, n.Name)
-			}
-			return oasis.TextResult(out.String()), nil
+			return oasis.TextResult(formatSnapshot(snap)), nil
 		})
 }
 
+// formatSnapshot renders a snapshot as the url/title header followed by one
+// "[ref] role "name"" line per node.
+func formatSnapshot(snap PageSnapshot) string {
+	var out strings.Builder
+	fmt.Fprintf(&out, "url: %s\ntitle: %s\n", snap.URL, snap.Title)
+	for _, n := range snap.Nodes {
+		fmt.Fprintf(&out, "[%s] %s %q\n", n.Ref, n.Role, n.Name)
+	}
+	return out.String()
+}
+
 func pageTextTool(sb BrowserSandbox) toolImpl {
 	return newTool("page_text",
 		"Extract readable text content from the current browser page. Ideal for RAG and information gathering — much cheaper than screenshots.",
@@ -687,6 +735,7 @@ func webSearchTool(sb Sandbox) toolImpl {
 			if err != nil {
 				return oasis.ToolResult{Error: err.Error()}, nil
 			}
+			res.Results = dedupWebResults(res.Results)
 			if len(res.Results) == 0 {
 				return oasis.TextResult("No results found for: " + p.Query), nil
 			}
@@ -703,6 +752,62 @@ func webSearchTool(sb Sandbox) toolImpl {
 		})
 }
 
+// trackingParams are query parameters that identify a campaign or referrer
+// rather than the page; two URLs differing only in these are the same page.
+// Plain "ref" is not listed: sites such as GitHub use it to pick a branch or
+// tag, so it can change the page.
+var trackingParams = map[string]bool{
+	"fbclid": true, "gclid": true, "dclid": true, "msclkid": true,
+	"mc_cid": true, "mc_eid": true, "ref_src": true,
+}
+
+// normalizeResultURL reduces a search result URL to a key identifying its
+// page: scheme and "www." are dropped, the host is lowercased, tracking
+// parameters (utm_* and trackingParams), the fragment, and a trailing slash
+// are removed. Unparseable URLs are returned trimmed.
+func normalizeResultURL(raw string) string {
+	raw = strings.TrimSpace(raw)
+	u, err := url.Parse(raw)
+	if err != nil || u.Host == "" {
+		return raw
+	}
+	host := strings.TrimPrefix(strings.ToLower(u.Host), "www.")
+	q := u.Query()
+	for k := range q {
+		if lk := strings.ToLower(k); strings.HasPrefix(lk, "utm_") || trackingParams[lk] {
+			q.Del(k)
+		}
+	}
+	key := host + strings.TrimSuffix(u.EscapedPath(), "/")
+	if len(q) > 0 {
+		key += "?" + q.Encode() // Encode sorts keys, so parameter order doesn't matter
+	}
+	return key
+}
+
+// dedupWebResults drops results that repeat an earlier one, keeping the
+// first (highest-ranked) copy. Two results are the same when their URLs
+// normalize to the same key or their snippets are identical up to case and
+// whitespace — the same article syndicated under different URLs.
+func dedupWebResults(items []WebSearchResultItem) []WebSearchResultItem {
+	seenURL := make(map[string]bool, len(items))
+	seenSnippet := make(map[string]bool, len(items))
+	out := items[:0:0]
+	for _, it := range items {
+		u := normalizeResultURL(it.URL)
+		sn := strings.ToLower(strings.Join(strings.Fields(it.Snippet), " "))
+		if seenURL[u] || (sn != "" && seenSnippet[sn]) {
+			continue
+		}
+		seenURL[u] = true
+		if sn != "" {
+			seenSnippet[sn] = true
+		}
+		out = append(out, it)
+	}
+	return out
+}
+
 func browserEvalTool(sb BrowserSandbox) toolImpl {
 	return newTool("browser_eval",
 		"Execute JavaScript in the current browser tab. Useful for reading form values, checking element states, extracting data, or interacting with page APIs that aren't accessible through the accessibility tree.",
@@ -737,6 +842,131 @@ func browserFindTool(sb BrowserSandbox) toolImpl {
 		})
 }
 
+func browserFillFormTool(sb BrowserSandbox) toolImpl {
+	return newTool("browser_fill_form",
+		"Fill several form fields in one call. Each field is matched by its label against the current page's interactive elements, then filled (or selected, for dropdowns). Returns a per-field report and a fresh snapshot; fields that could not be found are reported, not guessed. Submit the form separately with the browser tool.",
+		string(core.DeriveSchema[browserFillFormArgs]()),
+		func(ctx context.Context, args json.RawMessage) (oasis.ToolResult, error) {
+			var p browserFillFormArgs
+			if err := json.Unmarshal(args, &p); err != nil {
+				return oasis.ToolResult{Error: "invalid args: " + err.Error()}, nil
+			}
+			if len(p.Fields) == 0 {
+				return oasis.ToolResult{Error: "fields is required"}, nil
+			}
+			snap, err := sb.BrowserSnapshot(ctx, SnapshotOpts{Filter: "interactive"})
+			if err != nil {
+				return oasis.ToolResult{Error: err.Error()}, nil
+			}
+
+			var report strings.Builder
+			used := make(map[string]bool)
+			filled := 0
+			for _, f := range p.Fields {
+				node, ok := matchFormField(snap.Nodes, f.Label, used)
+				if !ok {
+					fmt.Fprintf(&report, "- %s: no matching field\n", f.Label)
+					continue
+				}
+				used[node.Ref] = true
+				action := BrowserAction{Type: "fill", Ref: node.Ref, Text: f.Value}
+				if node.Role == "combobox" || node.Role == "listbox" {
+					action = BrowserAction{Type: "select", Ref: node.Ref, Value: f.Value}
+				}
+				res, err := sb.BrowserAction(ctx, action)
+				// Why: an ARIA combobox is often an autocomplete text input
+				// rather than a <select>, so typing is the next best try.
+				if node.Role == "combobox" && (err != nil || !res.Success) {
+					res, err = sb.BrowserAction(ctx, BrowserAction{Type: "fill", Ref: node.Ref, Text: f.Value})
+				}
+				switch {
+				case err != nil:
+					fmt.Fprintf(&report, "- %s → [%s] %q: failed: %v\n", f.Label, node.Ref, node.Name, err)
+				case !res.Success:
+					fmt.Fprintf(&report, "- %s → [%s] %q: failed: %s\n", f.Label, node.Ref, node.Name, res.Message)
+				default:
+					filled++
+					fmt.Fprintf(&report, "- %s → [%s] %q: ok\n", f.Label, node.Ref, node.Name)
+				}
+			}
+
+			out := fmt.Sprintf("filled %d of %d fields\n%s", filled, len(p.Fields), report.String())
+			if after, err := sb.BrowserSnapshot(ctx, SnapshotOpts{Filter: "interactive"}); err == nil {
+				out += "\n" + formatSnapshot(after)
+			}
+			return oasis.TextResult(out), nil
+		})
+}
+
+// formFieldRoles are the snapshot roles browser_fill_form can enter a value
+// into.
+var formFieldRoles = map[string]bool{
+	"textbox": true, "searchbox": true, "spinbutton": true, "combobox": true, "listbox": true,
+}
+
+// matchFormField returns the fillable node whose accessible name best
+// matches label, skipping refs in used. An exact match (ignoring case,
+// punctuation such as "*" or ":", and word breaks, so "Date-of-birth"
+// matches "Date of birth") beats one name containing the other, which
+// beats partial word overlap; overlap needs at least half of the label's
+// words. Ties go to the earlier node.
+func matchFormField(nodes []SnapshotNode, label string, used map[string]bool) (SnapshotNode, bool) {
+	want := formWords(label)
+	if len(want) == 0 {
+		return SnapshotNode{}, false
+	}
+	wantStr := strings.Join(want, " ")
+	var (
+		best      SnapshotNode
+		bestScore float64
+	)
+	for _, n := range nodes {
+		if !formFieldRoles[n.Role] || used[n.Ref] {
+			continue
+		}
+		have := formWords(n.Name)
+		if len(have) == 0 {
+			continue
+		}
+		haveStr := strings.Join(have, " ")
+		var score float64
+		switch {
+		case haveStr == wantStr || strings.Join(have, "") == strings.Join(want, ""):
+			score = 3
+		case strings.Contains(" "+haveStr+" ", " "+wantStr+" ") || strings.Contains(" "+wantStr+" ", " "+haveStr+" "):
+			score = 2
+		default:
+			common := 0
+			for _, w := range want {
+				if slices.Contains(have, w) {
+					common++
+				}
+			}
+			if ratio := float64(common) / float64(len(want)); ratio >= 0.5 {
+				score = ratio
+			}
+		}
+		if score > bestScore {
+			best, bestScore = n, score
+		}
+	}
+	return best, bestScore > 0
+}
+
+// formWords lowercases s and splits it into letter/digit words. Hyphens
+// join rather than split, so "E-mail" is the single word "email".
+func formWords(s string) []string {
+	s = strings.Map(func(r rune) rune {
+		if unicode.Is(unicode.Hyphen, r) {
+			return -1
+		}
+		return unicode.ToLower(r)
+	}, s)
+	return strings.FieldsFunc(s, func(r rune) bool {
+		return !unicode.IsLetter(r) && !unicode.IsDigit(r)
+	})
+}
+
 func browserWaitTool(sb BrowserSandbox) toolImpl {
 	return newTool("browser_wait",
 		"Wait for a page condition after navigate/click instead of polling with screenshots. Returns satisfied=false on timeout (never errors) — if not satisfied, take a snapshot to inspect the actual page state.",
@@ -35,6 +35,7 @@ type mockSandbox struct {
 	browserTextFn  func(ctx context.Context, opts TextOpts) (BrowserTextResult, error)
 	browserPDFFn   func(ctx context.Context) ([]byte, error)
 	browserWaitFn  func(ctx context.Context, opts BrowserWaitOpts) (BrowserWaitResult, error)
+	httpFetchFn    func(ctx context.Context, req HTTPFetchRequest) (HTTPFetchResult, error)
 }
 
 func (m *mockSandbox) Shell(ctx context.Context, req ShellRequest) (ShellResult, error) {
@@ -151,6 +152,9 @@ func (m *mockSandbox) Tree(ctx context.Context, req TreeRequest) (TreeResult, er
 }
 
 func (m *mockSandbox) HTTPFetch(ctx context.Context, req HTTPFetchRequest) (HTTPFetchResult, error) {
+	if m.httpFetchFn != nil {
+		return m.httpFetchFn(ctx, req)
+	}
 	return HTTPFetchResult{}, nil
 }
 
@@ -398,26 +402,27 @@ func TestToolDefinitionsComplete(t *testing.T) {
 	tools := Tools(sb)
 
 	expected := map[string]bool{
-		"shell":          false,
-		"execute_code":   false,
-		"file_read":      false,
-		"file_write":     false,
-		"file_edit":      false,
-		"file_glob":      false,
-		"file_grep":      false,
-		"file_tree":      false,
-		"http_fetch":     false,
-		"workspace_info": false,
-		"browser":        false,
-		"screenshot":     false,
-		"mcp_call":       false,
-		"snapshot":       false,
-		"page_text":      false,
-		"export_pdf":     false,
-		"browser_eval":   false,
-		"browser_find":   false,
-		"browser_wait":   false,
-		"web_search":     false,
+		"shell":             false,
+		"execute_code":      false,
+		"file_read":         false,
+		"file_write":        false,
+		"file_edit":         false,
+		"file_glob":         false,
+		"file_grep":         false,
+		"file_tree":         false,
+		"http_fetch":        false,
+		"workspace_info":    false,
+		"browser":           false,
+		"screenshot":        false,
+		"mcp_call":          false,
+		"snapshot":          false,
+		"page_text":         false,
+		"export_pdf":        false,
+		"browser_eval":      false,
+		"browser_find":      false,
+		"browser_wait":      false,
+		"browser_fill_form": false,
+		"web_search":        false,
 	}
 
 	for _, tool := range tools {
@@ -452,8 +457,8 @@ func TestToolDefinitionsComplete(t *testing.T) {
 		}
 	}
 
-	if len(tools) != 20 {
-		t.Errorf("got %d tools, want 20", len(tools))
+	if len(tools) != 21 {
+		t.Errorf("got %d tools, want 21", len(tools))
 	}
 }
 
@@ -1096,6 +1101,10 @@ func TestFindMountForPath(t *testing.T) {
 		{"/tmp/scratch", "", ""},
 		{"/workspace/other.txt", "", ""},
 		{"/workspace/inputs2/x", "", ""}, // not under /workspace/inputs
+		{"/workspace/output/./sub//x.txt", "/workspace/output", "sub/x.txt"},
+		{"/workspace/output/../inputs/data.csv", "/workspace/inputs", "data.csv"},
+		{"/workspace/output/../secrets/key", "", ""}, // escapes every mount
+		{"output/report.md", "", ""},                 // relative: cwd unknown
 	}
 	for _, c := range cases {
 		got, key := findMountForPath(mounts, c.path)
@@ -1129,7 +1138,7 @@ func TestTools_WithoutBrowserOmitsBrowserTools(t *testing.T) {
 	browserNames := map[string]bool{
 		"browser": true, "screenshot": true, "snapshot": true,
 		"page_text": true, "export_pdf": true, "browser_eval": true,
-		"browser_find": true, "browser_wait": true,
+		"browser_find": true, "browser_wait": true, "browser_fill_form": true,
 	}
 
 	full := Tools(sb)
@@ -1167,7 +1176,7 @@ func TestTools_NonBrowserSandboxOmitsBrowserTools(t *testing.T) {
 	browserNames := map[string]bool{
 		"browser": true, "screenshot": true, "snapshot": true,
 		"page_text": true, "export_pdf": true, "browser_eval": true,
-		"browser_find": true, "browser_wait": true,
+		"browser_find": true, "browser_wait": true, "browser_fill_form": true,
 	}
 
 	tools := Tools(lightSandbox{})
@@ -1182,8 +1191,8 @@ func TestTools_NonBrowserSandboxOmitsBrowserTools(t *testing.T) {
 	}
 
 	// A browser-capable sandbox still gets the full set.
-	if got := len(Tools(&mockSandbox{})); got != 20 {
-		t.Errorf("got %d tools for browser sandbox, want 20", got)
+	if got := len(Tools(&mockSandbox{})); got != 21 {
+		t.Errorf("got %d tools for browser sandbox, want 21", got)
 	}
 }
 
@@ -1407,3 +1416,171 @@ func TestFindMountForPathPrefersDeepest(t *testing.T) {
 		t.Errorf("key = %q, want %q", key, "report.md")
 	}
 }
+
+func TestNormalizeResultURL(t *testing.T) {
+	same := []string{
+		"https://example.com/post",
+		"http://www.Example.com/post/",
+		"https://example.com/post?utm_source=x&utm_medium=y",
+		"https://example.com/post#comments",
+		"https://example.com/post?fbclid=abc",
+	}
+	want := normalizeResultURL(same[0])
+	for _, u := range same[1:] {
+		if got := normalizeResultURL(u); got != want {
+			t.Errorf("normalizeResultURL(%q) = %q, want %q", u, got, want)
+		}
+	}
+	if a, b := normalizeResultURL("https://example.com/post?id=1&p=2"), normalizeResultURL("https://example.com/post?p=2&id=1"); a != b {
+		t.Errorf("parameter order changed the key: %q vs %q", a, b)
+	}
+	if a, b := normalizeResultURL("https://example.com/post?id=1"), normalizeResultURL("https://example.com/post?id=2"); a == b {
+		t.Error("meaningful query parameters must be kept")
+	}
+	if a, b := normalizeResultURL("https://github.com/o/r/blob/x?ref=main"), normalizeResultURL("https://github.com/o/r/blob/x?ref=v1"); a == b {
+		t.Error("ref selects a branch on some sites and must be kept")
+	}
+}
+
+func TestDedupWebResults(t *testing.T) {
+	items := []WebSearchResultItem{
+		{Title: "A", URL: "https://example.com/a", Snippet: "Original article text."},
+		{Title: "A tracked", URL: "http://www.example.com/a/?utm_campaign=z"},
+		{Title: "A mirror", URL: "https://mirror.net/a", Snippet: "  original   ARTICLE text. "},
+		{Title: "B", URL: "https://example.com/b", Snippet: "Something else."},
+	}
+	got := dedupWebResults(items)
+	if len(got) != 2 || got[0].Title != "A" || got[1].Title != "B" {
+		t.Fatalf("dedupWebResults = %+v, want A and B", got)
+	}
+}
+
+func TestMatchFormField(t *testing.T) {
+	nodes := []SnapshotNode{
+		{Ref: "e0", Role: "heading", Name: "Email"},
+		{Ref: "e1", Role: "textbox", Name: "First name *"},
+		{Ref: "e2", Role: "textbox", Name: "Last name *"},
+		{Ref: "e3", Role: "textbox", Name: "Email address"},
+		{Ref: "e4", Role: "combobox", Name: "Country"},
+		{Ref: "e5", Role: "button", Name: "Submit"},
+	}
+	cases := []struct {
+		label, want string
+	}{
+		{"first name", "e1"},
+		{"Last Name:", "e2"},
+		{"E-mail", "e3"},        // the hyphen joins: "email" is in "email address"
+		{"email", "e3"},         // contained in the name; the heading is not fillable
+		{"Email-Address", "e3"}, // word breaks do not matter for an exact match
+		{"country", "e4"},       // dropdowns are fillable
+		{"submit", ""},          // buttons are not
+		{"phone number", ""},    // nothing close
+	}
+	for _, c := range cases {
+		got, ok := matchFormField(nodes, c.label, nil)
+		if c.want == "" {
+			if ok {
+				t.Errorf("matchFormField(%q) = %s, want no match", c.label, got.Ref)
+			}
+			continue
+		}
+		if !ok || got.Ref != c.want {
+			t.Errorf("matchFormField(%q) = %s (ok=%v), want %s", c.label, got.Ref, ok, c.want)
+		}
+	}
+
+	// A field already filled is not matched again.
+	if got, _ := matchFormField(nodes, "name", map[string]bool{"e1": true}); got.Ref != "e2" {
+		t.Errorf("with e1 used, matchFormField(name) = %s, want e2", got.Ref)
+	}
+}
+
+func TestBrowserFillFormTool(t *testing.T) {
+	var actions []BrowserAction
+	sb := &mockSandbox{
+		snapshotFn: func(context.Context, SnapshotOpts) (PageSnapshot, error) {
+			return PageSnapshot{Nodes: []SnapshotNode{
+				{Ref: "e1", Role: "textbox", Name: "Email"},
+				{Ref: "e2", Role: "combobox", Name: "Plan"},
+			}}, nil
+		},
+		browserActFn: func(_ context.Context, a BrowserAction) (BrowserResult, error) {
+			actions = append(actions, a)
+			return BrowserResult{Success: true}, nil
+		},
+	}
+	tool := findToolByName(Tools(sb), "browser_fill_form")
+	res, err := tool.ExecuteRaw(context.Background(), json.RawMessage(
+		`{"fields":[{"label":"email","value":"a@b.c"},{"label":"plan","value":"pro"},{"label":"coupon","value":"X"}]}`))
+	if err != nil || res.Error != "" {
+		t.Fatalf("ExecuteRaw = %v, %q", err, res.Error)
+	}
+	if len(actions) != 2 ||
+		actions[0] != (BrowserAction{Type: "fill", Ref: "e1", Text: "a@b.c"}) ||
+		actions[1] != (BrowserAction{Type: "select", Ref: "e2", Value: "pro"}) {
+		t.Errorf("actions = %+v", actions)
+	}
+	if !strings.Contains(res.Content, "filled 2 of 3 fields") || !strings.Contains(res.Content, "coupon: no matching field") {
+		t.Errorf("report = %q", res.Content)
+	}
+}
+
+func TestBrowserFillFormTool_ComboboxFallsBackToFill(t *testing.T) {
+	var actions []BrowserAction
+	sb := &mockSandbox{
+		snapshotFn: func(context.Context, SnapshotOpts) (PageSnapshot, error) {
+			return PageSnapshot{Nodes: []SnapshotNode{{Ref: "e1", Role: "combobox", Name: "City"}}}, nil
+		},
+		browserActFn: func(_ context.Context, a BrowserAction) (BrowserResult, error) {
+			actions = append(actions, a)
+			if a.Type == "select" {
+				return BrowserResult{Message: "element is not a <select>"}, nil
+			}
+			return BrowserResult{Success: true}, nil
+		},
+	}
+	tool := findToolByName(Tools(sb), "browser_fill_form")
+	res, err := tool.ExecuteRaw(context.Background(), json.RawMessage(`{"fields":[{"label":"city","value":"Oslo"}]}`))
+	if err != nil || res.Error != "" {
+		t.Fatalf("ExecuteRaw = %v, %q", err, res.Error)
+	}
+	if len(actions) != 2 || actions[1] != (BrowserAction{Type: "fill", Ref: "e1", Text: "Oslo"}) {
+		t.Errorf("actions = %+v, want select then fill", actions)
+	}
+	if !strings.Contains(res.Content, "filled 1 of 1 fields") {
+		t.Errorf("report = %q", res.Content)
+	}
+}
+
+func TestHTTPFetchToolMaxChars(t *testing.T) {
+	var captured HTTPFetchRequest
+	sb := &mockSandbox{
+		httpFetchFn: func(_ context.Context, req HTTPFetchRequest) (HTTPFetchResult, error) {
+			captured = req
+			return HTTPFetchResult{Content: "ok"}, nil
+		},
+	}
+
+	cases := []struct {
+		name string
+		opts []ToolsOption
+		args string
+		want int
+	}{
+		{"shared default", nil, `{"url":"https://example.com"}`, 8000},
+		{"configured cap", []ToolsOption{WithHTTPFetchMaxChars(1000)}, `{"url":"https://example.com"}`, 1000},
+		{"model cannot raise cap", []ToolsOption{WithHTTPFetchMaxChars(1000)}, `{"url":"https://example.com","max_chars":50000}`, 1000},
+		{"model can lower cap", []ToolsOption{WithHTTPFetchMaxChars(1000)}, `{"url":"https://example.com","max_chars":200}`, 200},
+	}
+	for _, tc := range cases {
+		t.Run(tc.name, func(t *testing.T) {
+			tool := findToolByName(Tools(sb, tc.opts...), "http_fetch")
+			if _, err := tool.ExecuteRaw(context.Background(), json.RawMessage(tc.args)); err != nil {
+				t.Fatalf("unexpected error: %v", err)
+			}
+			if captured.MaxChars != tc.want {
+				t.Errorf("MaxChars = %d, want %d", captured.MaxChars, tc.want)
+			}
+		})
+	}
+}
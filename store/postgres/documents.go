@@ -3,10 +3,13 @@ package postgres
 import (
 	"context"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"strings"
 	"time"
 
+	"github.com/jackc/pgx/v5"
+
 	oasis "github.com/nevindra/oasis/core"
 )
 
@@ -231,6 +234,60 @@ func (s *Store) ListDocumentMeta(ctx context.Context, limit int) ([]oasis.Docume
 	return docs, rows.Err()
 }
 
+// ListDocumentStats returns documents with their chunk counts and text size,
+// ordered by chunk count (largest first), without the Content field.
+func (s *Store) ListDocumentStats(ctx context.Context, limit int) ([]oasis.DocumentStats, error) {
+	start := time.Now()
+	s.logger.Debug("postgres: list document stats", "limit", limit)
+	query := `SELECT d.id, d.title, d.source, d.created_at, COUNT(c.id),
+		 OCTET_LENGTH(d.content) + COALESCE(SUM(OCTET_LENGTH(c.content)), 0)
+		 FROM documents d LEFT JOIN chunks c ON c.document_id = d.id
+		 GROUP BY d.id
+		 ORDER BY COUNT(c.id) DESC, d.created_at DESC`
+	var args []any
+	if limit > 0 {
+		query += ` LIMIT $1`
+		args = append(args, limit)
+	}
+	rows, err := s.pool.Query(ctx, query, args...)
+	if err != nil {
+		s.logger.Error("postgres: list document stats failed", "error", err, "duration", time.Since(start))
+		return nil, fmt.Errorf("postgres: list document stats: %w", err)
+	}
+	defer rows.Close()
+
+	var stats []oasis.DocumentStats
+	for rows.Next() {
+		var d oasis.DocumentStats
+		if err := rows.Scan(&d.ID, &d.Title, &d.Source, &d.CreatedAt, &d.ChunkCount, &d.ContentBytes); err != nil {
+			return nil, fmt.Errorf("postgres: scan document stats: %w", err)
+		}
+		stats = append(stats, d)
+	}
+	s.logger.Debug("postgres: list document stats ok", "count", len(stats), "duration", time.Since(start))
+	return stats, rows.Err()
+}
+
+// FindDocumentBySource returns the newest document with the given source,
+// without its Content, or nil when none exists.
+func (s *Store) FindDocumentBySource(ctx context.Context, source string) (*oasis.Document, error) {
+	var d oasis.Document
+	err := s.pool.QueryRow(ctx,
+		`SELECT id, title, source, created_at
+		 FROM documents
+		 WHERE source = $1
+		 ORDER BY created_at DESC
+		 LIMIT 1`,
+		source).Scan(&d.ID, &d.Title, &d.Source, &d.CreatedAt)
+	if errors.Is(err, pgx.ErrNoRows) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("postgres: find document by source: %w", err)
+	}
+	return &d, nil
+}
+
 // DeleteDocument removes a document and all its chunks in a single transaction.
 func (s *Store) DeleteDocument(ctx context.Context, id string) error {
 	start := time.Now()
@@ -87,6 +87,7 @@ var _ oasis.GraphStore = (*Store)(nil)
 var _ oasis.BidirectionalGraphStore = (*Store)(nil)
 var _ oasis.CheckpointStore = (*Store)(nil)
 var _ oasis.DocumentMetaLister = (*Store)(nil)
+var _ oasis.DocumentStatsLister = (*Store)(nil)
 var _ oasis.ScheduledActionStore = (*Store)(nil)
 
 // nopLogger is a logger that discards all output.
@@ -233,6 +234,7 @@ func (s *Store) Init(ctx context.Context) error {
 			metadata JSONB
 		)`, vtype),
 		`CREATE INDEX IF NOT EXISTS chunks_document_idx ON chunks(document_id)`,
+		`CREATE INDEX IF NOT EXISTS documents_source_idx ON documents(source)`,
 	)
 	if useHNSW {
 		stmts = append(stmts, fmt.Sprintf(`CREATE INDEX IF NOT EXISTS chunks_embedding_idx ON chunks USING hnsw (embedding vector_cosine_ops)%s`, hnswWith))
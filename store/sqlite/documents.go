@@ -4,6 +4,7 @@ import (
 	"context"
 	"database/sql"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"sort"
 	"strings"
@@ -260,6 +261,57 @@ func (s *Store) ListDocumentMeta(ctx context.Context, limit int) ([]oasis.Docume
 	return docs, rows.Err()
 }
 
+// ListDocumentStats returns documents with their chunk counts and text size,
+// ordered by chunk count (largest first), without the Content field.
+func (s *Store) ListDocumentStats(ctx context.Context, limit int) ([]oasis.DocumentStats, error) {
+	start := time.Now()
+	s.logger.Debug("sqlite: list document stats", "limit", limit)
+
+	query := `SELECT d.id, d.title, d.source, d.created_at, COUNT(c.id),
+		 LENGTH(CAST(d.content AS BLOB)) + COALESCE(SUM(LENGTH(CAST(c.content AS BLOB))), 0)
+		 FROM documents d LEFT JOIN chunks c ON c.document_id = d.id
+		 GROUP BY d.id
+		 ORDER BY COUNT(c.id) DESC, d.created_at DESC`
+	var args []any
+	if limit > 0 {
+		query += ` LIMIT ?`
+		args = append(args, limit)
+	}
+	rows, err := s.db.QueryContext(ctx, query, args...)
+	if err != nil {
+		s.logger.Error("sqlite: list document stats failed", "error", err)
+		return nil, fmt.Errorf("list document stats: %w", err)
+	}
+	defer rows.Close()
+
+	var stats []oasis.DocumentStats
+	for rows.Next() {
+		var d oasis.DocumentStats
+		if err := rows.Scan(&d.ID, &d.Title, &d.Source, &d.CreatedAt, &d.ChunkCount, &d.ContentBytes); err != nil {
+			return nil, fmt.Errorf("scan document stats: %w", err)
+		}
+		stats = append(stats, d)
+	}
+	s.logger.Debug("sqlite: list document stats ok", "count", len(stats), "duration", time.Since(start))
+	return stats, rows.Err()
+}
+
+// FindDocumentBySource returns the newest document with the given source,
+// without its Content, or nil when none exists.
+func (s *Store) FindDocumentBySource(ctx context.Context, source string) (*oasis.Document, error) {
+	var d oasis.Document
+	err := s.db.QueryRowContext(ctx,
+		`SELECT id, title, source, created_at FROM documents WHERE source = ? ORDER BY created_at DESC LIMIT 1`,
+		source).Scan(&d.ID, &d.Title, &d.Source, &d.CreatedAt)
+	if errors.Is(err, sql.ErrNoRows) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("find document by source: %w", err)
+	}
+	return &d, nil
+}
+
 // DeleteDocument removes a document, its chunks, and associated FTS entries.
 func (s *Store) DeleteDocument(ctx context.Context, id string) error {
 	start := time.Now()
@@ -164,6 +164,76 @@ func TestStoreDocument(t *testing.T) {
 	}
 }
 
+func TestFindDocumentBySource(t *testing.T) {
+	s := testStore(t)
+	ctx := context.Background()
+
+	if d, err := s.FindDocumentBySource(ctx, "notes.md"); err != nil || d != nil {
+		t.Fatalf("empty store: got %+v, %v; want nil, nil", d, err)
+	}
+
+	older := oasis.Document{ID: oasis.NewID(), Title: "v1", Source: "notes.md", Content: "one", CreatedAt: 100}
+	newer := oasis.Document{ID: oasis.NewID(), Title: "v2", Source: "notes.md", Content: "two", CreatedAt: 200}
+	other := oasis.Document{ID: oasis.NewID(), Title: "x", Source: "other.md", Content: "x", CreatedAt: 300}
+	for _, d := range []oasis.Document{older, newer, other} {
+		if err := s.StoreDocument(ctx, d, nil); err != nil {
+			t.Fatalf("StoreDocument: %v", err)
+		}
+	}
+
+	d, err := s.FindDocumentBySource(ctx, "notes.md")
+	if err != nil {
+		t.Fatalf("FindDocumentBySource: %v", err)
+	}
+	if d == nil || d.ID != newer.ID {
+		t.Fatalf("got %+v, want the newest notes.md document", d)
+	}
+	if d.Content != "" {
+		t.Error("expected Content to be omitted")
+	}
+}
+
+func TestListDocumentStats(t *testing.T) {
+	s := testStore(t)
+	ctx := context.Background()
+
+	chunksOf := func(docID string, n int) []oasis.Chunk {
+		var cs []oasis.Chunk
+		for i := range n {
+			cs = append(cs, oasis.Chunk{ID: oasis.NewID(), DocumentID: docID, Content: "abcd", ChunkIndex: i})
+		}
+		return cs
+	}
+	small := oasis.Document{ID: oasis.NewID(), Title: "small", Source: "s.md", Content: "xy", CreatedAt: 300}
+	large := oasis.Document{ID: oasis.NewID(), Title: "large", Source: "l.md", Content: "xyz", CreatedAt: 100}
+	empty := oasis.Document{ID: oasis.NewID(), Title: "empty", Source: "e.md", Content: "", CreatedAt: 200}
+	for _, tc := range []struct {
+		doc oasis.Document
+		n   int
+	}{{small, 1}, {large, 3}, {empty, 0}} {
+		if err := s.StoreDocument(ctx, tc.doc, chunksOf(tc.doc.ID, tc.n)); err != nil {
+			t.Fatalf("StoreDocument: %v", err)
+		}
+	}
+
+	stats, err := s.ListDocumentStats(ctx, 0)
+	if err != nil {
+		t.Fatalf("ListDocumentStats: %v", err)
+	}
+	var got []string
+	for _, d := range stats {
+		got = append(got, fmt.Sprintf("%s:%d:%d", d.Title, d.ChunkCount, d.ContentBytes))
+	}
+	want := []string{"large:3:15", "small:1:6", "empty:0:0"}
+	if fmt.Sprint(got) != fmt.Sprint(want) {
+		t.Errorf("stats = %v, want %v", got, want)
+	}
+
+	if top, err := s.ListDocumentStats(ctx, 1); err != nil || len(top) != 1 || top[0].ID != large.ID {
+		t.Errorf("limit 1: got %+v, %v; want only the largest document", top, err)
+	}
+}
+
 func TestSearchMessages(t *testing.T) {
 	s := testStore(t)
 	ctx := context.Background()
@@ -494,3 +564,34 @@ func TestCosineSimilarity(t *testing.T) {
 		t.Errorf("opposite vectors: expected ~-1.0, got %f", s)
 	}
 }
+
+func TestBackupReopens(t *testing.T) {
+	s := testStore(t)
+	ctx := context.Background()
+
+	now := oasis.NowUnix()
+	thread := oasis.Thread{ID: oasis.NewID(), ChatID: "chat-1", CreatedAt: now, UpdatedAt: now}
+	s.CreateThread(ctx, thread)
+	if err := s.StoreMessage(ctx, oasis.Message{ID: oasis.NewID(), ThreadID: thread.ID, Role: "user", Content: "keep me", CreatedAt: now}); err != nil {
+		t.Fatalf("StoreMessage: %v", err)
+	}
+
+	path := filepath.Join(t.TempDir(), "snapshot.db")
+	if err := s.Backup(ctx, path); err != nil {
+		t.Fatalf("Backup: %v", err)
+	}
+	// A second backup to the same path must fail rather than overwrite.
+	if err := s.Backup(ctx, path); err == nil {
+		t.Error("expected error backing up over an existing file")
+	}
+
+	restored := New(path)
+	defer restored.Close()
+	got, err := restored.GetMessages(ctx, thread.ID, 10)
+	if err != nil {
+		t.Fatalf("GetMessages on backup: %v", err)
+	}
+	if len(got) != 1 || got[0].Content != "keep me" {
+		t.Errorf("backup messages = %+v, want one message %q", got, "keep me")
+	}
+}
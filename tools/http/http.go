@@ -2,10 +2,12 @@ package http
 
 import (
 	"context"
+	"errors"
 	"fmt"
 	"io"
 	"net/http"
 	"net/url"
+	"regexp"
 	"strings"
 	"time"
 	"unicode/utf8"
@@ -16,14 +18,62 @@ import (
 	"github.com/nevindra/oasis/ingest"
 )
 
-// defaultFetchMaxChars is the default character cap applied to extracted
-// readable content. Callers may override via FetchInput.MaxChars.
-const defaultFetchMaxChars = 8000
+// DefaultMaxChars is the default character cap applied to extracted
+// readable content. Override per tool with WithMaxChars; FetchInput.MaxChars
+// can only lower it per call. The sandbox http_fetch tool defaults to the
+// same value (see sandbox.WithHTTPFetchMaxChars).
+const DefaultMaxChars = 8000
+
+// ErrBlocked is returned (wrapped) by Fetch when the server answered with a
+// CAPTCHA, bot-check, or consent interstitial instead of the requested page.
+// The interstitial's text is never returned as content: it would read as a
+// successful fetch of an empty or irrelevant page. Match with errors.Is.
+var ErrBlocked = errors.New("blocked by interstitial")
+
+// interstitialMarkers maps lowercase substrings of known interstitial pages
+// to a short human-readable reason. Markers are deliberately specific to the
+// challenge pages themselves — a bare "g-recaptcha" would also match ordinary
+// pages that embed a contact-form CAPTCHA.
+var interstitialMarkers = []struct {
+	marker string
+	reason string
+}{
+	{"our systems have detected unusual traffic", "CAPTCHA"},
+	{"/sorry/index?continue=", "CAPTCHA"},
+	{"<title>just a moment...</title>", "bot-check challenge"},
+	{"cf-chl-", "bot-check challenge"},
+	{"<title>attention required! | cloudflare</title>", "bot-check challenge"},
+	{"before you continue to google", "consent"},
+}
+
+// interstitialHosts maps hosts that only ever serve interstitials to a reason.
+// They are matched against the final response URL after redirects, never the
+// body: ordinary pages link to them too.
+var interstitialHosts = map[string]string{
+	"consent.google.com":  "consent",
+	"consent.youtube.com": "consent",
+}
+
+// detectInterstitial reports the reason a response from host with the given
+// HTML body looks like a CAPTCHA, bot-check, or consent interstitial, or ""
+// when it looks like real content.
+func detectInterstitial(host, html string) string {
+	if reason, ok := interstitialHosts[strings.ToLower(host)]; ok {
+		return reason
+	}
+	lower := strings.ToLower(html)
+	for _, m := range interstitialMarkers {
+		if strings.Contains(lower, m.marker) {
+			return m.reason
+		}
+	}
+	return ""
+}
 
 // FetchInput is the input payload for the http_fetch tool.
 type FetchInput struct {
 	URL      string `json:"url" describe:"URL to fetch"`
-	MaxChars int    `json:"max_chars,omitempty" describe:"Max characters to return (default 8000)"`
+	MaxChars int    `json:"max_chars,omitempty" describe:"Max characters to return (capped at the tool's limit)"`
 }
 
 // Tool fetches URLs and extracts readable content. It implements
@@ -31,14 +81,39 @@ type FetchInput struct {
 // kept as a bare string for ergonomic LLM consumption: the model just sees
 // the extracted text, and Erase wraps it as JSON automatically.
 type Tool struct {
-	client *http.Client
+	client   *http.Client
+	maxChars int
+}
+
+// Option configures a Tool.
+type Option func(*Tool)
+
+// WithHTTPClient sets a custom HTTP client (e.g. for a proxy or a different
+// timeout). The default client honors the HTTP_PROXY / HTTPS_PROXY / NO_PROXY
+// environment variables; supply a client whose Transport sets Proxy to route
+// through an explicit proxy instead.
+func WithHTTPClient(c *http.Client) Option {
+	return func(t *Tool) { t.client = c }
+}
+
+// WithMaxChars sets the cap on returned characters (default DefaultMaxChars).
+// It is a hard limit: FetchInput.MaxChars, which the model supplies, can lower
+// it per call but never raise it. Tools that call Fetch directly can share the
+// same limit through Truncate.
+func WithMaxChars(n int) Option {
+	return func(t *Tool) { t.maxChars = n }
 }
 
 // New creates an HTTPTool with a 15-second timeout.
-func New() *Tool {
-	return &Tool{
-		client: &http.Client{Timeout: 15 * time.Second},
+func New(opts ...Option) *Tool {
+	t := &Tool{
+		client:   &http.Client{Timeout: 15 * time.Second},
+		maxChars: DefaultMaxChars,
+	}
+	for _, opt := range opts {
+		opt(t)
 	}
+	return t
 }
 
 // Definition implements oasis.Tool.
@@ -49,17 +124,32 @@ func (t *Tool) Definition() oasis.ToolMeta {
 	}
 }
 
-// Execute implements oasis.Tool. Returns the extracted, possibly-truncated
-// readable text for the given URL. Truncation happens at a UTF-8 rune
-// boundary so the returned string is always valid UTF-8.
+// Execute implements oasis.Tool. Returns the extracted readable text for the
+// given URL, cut by Truncate to the tool's limit or a lower FetchInput.MaxChars.
 func (t *Tool) Execute(ctx context.Context, in FetchInput) (string, error) {
 	content, err := t.Fetch(ctx, in.URL)
 	if err != nil {
 		return "", err
 	}
-	limit := in.MaxChars
+	limit := t.maxChars
+	if limit <= 0 {
+		limit = DefaultMaxChars
+	}
+	if in.MaxChars > 0 && in.MaxChars < limit {
+		limit = in.MaxChars
+	}
+	return Truncate(content, limit), nil
+}
+
+// Truncate caps content at limit characters (runes) and marks the cut with
+// "... (truncated)". Rather than cutting mid-sentence it backs up to the last
+// paragraph break, then sentence end, then word break within the final fifth
+// of the allowed text, falling back to a hard cut at a rune boundary. The
+// result is always valid UTF-8; content within the limit is returned as is,
+// and a limit <= 0 means no limit.
+func Truncate(content string, limit int) string {
 	if limit <= 0 {
-		limit = defaultFetchMaxChars
+		return content
 	}
 	// Walk forward counting runes; stop at the byte offset that holds `limit`
 	// runes. Allocation-free: no []rune slice, no scratch buffer.
@@ -69,14 +159,67 @@ func (t *Tool) Execute(ctx context.Context, in FetchInput) (string, error) {
 		byteOff += size
 		runes++
 	}
-	if byteOff < len(content) {
-		content = content[:byteOff] + "\n... (truncated)"
+	if byteOff >= len(content) {
+		return content
+	}
+	kept := content[:byteOff]
+	floor := max(len(kept)*4/5, 1)
+	if i := strings.LastIndex(kept, "\n\n"); i >= floor {
+		kept = kept[:i]
+	} else if i := lastSentenceEnd(kept); i >= floor {
+		kept = kept[:i]
+	} else if i := strings.LastIndexAny(kept, " \t\n"); i >= floor {
+		kept = kept[:i]
+	}
+	return strings.TrimRight(kept, " \t\n") + "\n... (truncated)"
+}
+
+// lastSentenceEnd returns the byte offset just past the last sentence-ending
+// punctuation followed by whitespace in s, or 0 when there is none.
+func lastSentenceEnd(s string) int {
+	end := 0
+	for _, p := range []string{". ", "! ", "? ", ".\n", "!\n", "?\n"} {
+		end = max(end, strings.LastIndex(s, p)+1)
 	}
-	return content, nil
+	return end
+}
+
+// schemeRE matches a URL that starts with an explicit scheme (RFC 3986).
+var schemeRE = regexp.MustCompile(`^[A-Za-z][A-Za-z0-9+.-]*://`)
+
+// normalizeURL completes a URL the way a user types it: surrounding
+// whitespace is trimmed and a bare "www.example.com" or "example.com/path"
+// gets an https scheme. Schemes other than http and https are rejected.
+func normalizeURL(rawURL string) (string, error) {
+	rawURL = strings.TrimSpace(rawURL)
+	// Why: matching a leading scheme rather than parsing first — url.Parse
+	// reads "example.com:8080/x" as scheme "example.com", and a bare
+	// "example.com/login?next=https://x" contains "://" only in its query.
+	if !schemeRE.MatchString(rawURL) {
+		rawURL = "https://" + strings.TrimPrefix(rawURL, "//")
+	}
+	u, err := url.Parse(rawURL)
+	if err != nil {
+		return "", err
+	}
+	if s := strings.ToLower(u.Scheme); s != "http" && s != "https" {
+		return "", fmt.Errorf("unsupported scheme %q: only http and https URLs can be fetched", u.Scheme)
+	}
+	if u.Host == "" {
+		return "", errors.New("missing host")
+	}
+	return rawURL, nil
 }
 
 // Fetch downloads a URL and extracts readable text. Exported for use by other tools.
+// A URL without a scheme ("www.example.com/page") is fetched over https.
+// Returns an error wrapping ErrBlocked when the response is a CAPTCHA,
+// bot-check, or consent interstitial.
 func (t *Tool) Fetch(ctx context.Context, rawURL string) (string, error) {
+	rawURL, err := normalizeURL(rawURL)
+	if err != nil {
+		return "", fmt.Errorf("invalid URL: %w", err)
+	}
 	req, err := http.NewRequestWithContext(ctx, "GET", rawURL, nil)
 	if err != nil {
 		return "", fmt.Errorf("invalid URL: %w", err)
@@ -100,6 +243,13 @@ func (t *Tool) Fetch(ctx context.Context, rawURL string) (string, error) {
 
 	html := string(body)
 
+	// Why: challenge pages are HTTP 200 with a tiny body, so readability
+	// happily "extracts" them. Surface them as an error the LLM can act on
+	// (try another source or the browser) instead of as page content.
+	if reason := detectInterstitial(resp.Request.URL.Hostname(), html); reason != "" {
+		return "", fmt.Errorf("%w: %s served a %s page instead of content; try another source", ErrBlocked, rawURL, reason)
+	}
+
 	// Try readability extraction
 	parsedURL, _ := url.Parse(rawURL)
 	article, err := readability.FromReader(strings.NewReader(html), parsedURL)
@@ -3,8 +3,10 @@ package http
 import (
 	"context"
 	"encoding/json"
+	"errors"
 	"net/http"
 	"net/http/httptest"
+	"net/url"
 	"strings"
 	"testing"
 
@@ -58,6 +60,54 @@ func TestHTTPFetchTruncation(t *testing.T) {
 	}
 }
 
+func TestTruncate(t *testing.T) {
+	para := strings.Repeat("word ", 15) + "end.\n\n"        // 81 chars
+	sentences := strings.Repeat("Short sentence here. ", 5) // 105 chars
+	tests := []struct {
+		name    string
+		content string
+		limit   int
+		want    string
+	}{
+		{"fits", "short text", 100, "short text"},
+		{"paragraph break", para + para, 90, strings.TrimSpace(para) + "\n... (truncated)"},
+		{"sentence end", sentences, 90, strings.TrimSpace(strings.Repeat("Short sentence here. ", 4)) + "\n... (truncated)"},
+		{"word break", strings.Repeat("abcd ", 30), 52, strings.TrimSpace(strings.Repeat("abcd ", 10)) + "\n... (truncated)"},
+		{"no boundary", strings.Repeat("é", 20), 10, strings.Repeat("é", 10) + "\n... (truncated)"},
+		{"no limit", strings.Repeat("word ", 30), 0, strings.Repeat("word ", 30)},
+		{"negative limit", "some text", -1, "some text"},
+	}
+	for _, tt := range tests {
+		if got := Truncate(tt.content, tt.limit); got != tt.want {
+			t.Errorf("%s: Truncate = %q, want %q", tt.name, got, tt.want)
+		}
+	}
+}
+
+func TestHTTPFetchWithMaxChars(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.Write([]byte(strings.Repeat("A", 500)))
+	}))
+	defer srv.Close()
+
+	tool := New(WithMaxChars(100))
+	out, err := tool.Execute(context.Background(), FetchInput{URL: srv.URL})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if want := strings.Repeat("A", 100) + "\n... (truncated)"; out != want {
+		t.Errorf("got %d chars, want the configured 100 plus marker", len(out))
+	}
+	out, _ = tool.Execute(context.Background(), FetchInput{URL: srv.URL, MaxChars: 200})
+	if !strings.HasPrefix(out, strings.Repeat("A", 100)+"\n") {
+		t.Errorf("per-call MaxChars must not raise the tool limit, got %d chars", len(out))
+	}
+	out, _ = tool.Execute(context.Background(), FetchInput{URL: srv.URL, MaxChars: 50})
+	if !strings.HasPrefix(out, strings.Repeat("A", 50)+"\n") {
+		t.Errorf("per-call MaxChars should lower the tool limit, got %d chars", len(out))
+	}
+}
+
 // TestHTTPFetchErased verifies the tool works after Erase to AnyTool.
 func TestHTTPFetchErased(t *testing.T) {
 	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
@@ -105,3 +155,116 @@ func TestHTTPFetchErasedBadArgs(t *testing.T) {
 		t.Error("expected ToolResult.Error for bad args")
 	}
 }
+
+// googleSorryPage is a trimmed copy of the interstitial Google serves when it
+// flags automated traffic.
+const googleSorryPage = `<html><head><title>https://www.google.com/search?q=oasis</title></head>
+<body><div id="captcha-form"><script src="https://www.google.com/recaptcha/api.js"></script>
+<div class="g-recaptcha"></div></div>
+<div>Our systems have detected unusual traffic from your computer network.
+This page checks to see if it's really you sending the requests, and not a robot.</div>
+<form action="/sorry/index?continue=https://www.google.com/search%3Fq%3Doasis" method="post"></form>
+</body></html>`
+
+func TestDetectInterstitial(t *testing.T) {
+	cases := []struct {
+		name string
+		host string
+		html string
+		want string
+	}{
+		{"google captcha", "www.google.com", googleSorryPage, "CAPTCHA"},
+		{"cloudflare challenge", "example.com", `<html><head><title>Just a moment...</title></head><body><div id="cf-chl-widget"></div></body></html>`, "bot-check challenge"},
+		{"google consent", "www.google.com", `<html><body><h1>Before you continue to Google</h1></body></html>`, "consent"},
+		{"redirected to consent host", "consent.google.com", `<html><body><form></form></body></html>`, "consent"},
+		{"ordinary page linking to consent host", "example.com", `<html><body><p>Article</p><a href="https://consent.google.com/ml?continue=x">Privacy</a></body></html>`, ""},
+		{"ordinary page with form captcha", "example.com", `<html><body><p>Contact us</p><div class="g-recaptcha"></div></body></html>`, ""},
+		{"plain article", "example.com", `<html><body><p>Hello from test server</p></body></html>`, ""},
+	}
+	for _, tc := range cases {
+		t.Run(tc.name, func(t *testing.T) {
+			if got := detectInterstitial(tc.host, tc.html); got != tc.want {
+				t.Errorf("detectInterstitial = %q, want %q", got, tc.want)
+			}
+		})
+	}
+}
+
+func TestNormalizeURL(t *testing.T) {
+	cases := []struct {
+		in, want string
+		wantErr  bool
+	}{
+		{"https://example.com/a", "https://example.com/a", false},
+		{"  http://example.com  ", "http://example.com", false},
+		{"www.example.com", "https://www.example.com", false},
+		{"example.com/path?q=1", "https://example.com/path?q=1", false},
+		{"example.com:8080/x", "https://example.com:8080/x", false},
+		{"//example.com/x", "https://example.com/x", false},
+		{"example.com/login?next=https://x", "https://example.com/login?next=https://x", false},
+		{"ftp://example.com/file", "", true},
+		{"file:///etc/passwd", "", true},
+		{"", "", true},
+	}
+	for _, tc := range cases {
+		got, err := normalizeURL(tc.in)
+		if (err != nil) != tc.wantErr || got != tc.want {
+			t.Errorf("normalizeURL(%q) = %q, %v; want %q (error %v)", tc.in, got, err, tc.want, tc.wantErr)
+		}
+	}
+}
+
+func TestHTTPFetchCaptchaBlocked(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "text/html")
+		w.Write([]byte(googleSorryPage))
+	}))
+	defer srv.Close()
+
+	_, err := New().Fetch(context.Background(), srv.URL)
+	if !errors.Is(err, ErrBlocked) {
+		t.Fatalf("err = %v, want ErrBlocked", err)
+	}
+	if !strings.Contains(err.Error(), "CAPTCHA") {
+		t.Errorf("error %q does not name the interstitial kind", err)
+	}
+
+	// Through Erase the block is a business failure, not an infra error.
+	res, goErr := oasis.Erase[FetchInput, string](New()).ExecuteRaw(context.Background(), json.RawMessage(`{"url":"`+srv.URL+`"}`))
+	if goErr != nil {
+		t.Fatalf("unexpected Go error: %v", goErr)
+	}
+	if !strings.Contains(res.Error, "blocked by interstitial") {
+		t.Errorf("ToolResult.Error = %q, want blocked message", res.Error)
+	}
+}
+
+// TestHTTPFetchWithProxyClient verifies that a client supplied via
+// WithHTTPClient is used, so requests can be routed through a proxy.
+func TestHTTPFetchWithProxyClient(t *testing.T) {
+	var proxied string
+	proxy := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		// A forward proxy receives the absolute target URL.
+		proxied = r.URL.String()
+		w.Header().Set("Content-Type", "text/html")
+		w.Write([]byte("<html><body><p>Hello via proxy</p></body></html>"))
+	}))
+	defer proxy.Close()
+
+	proxyURL, err := url.Parse(proxy.URL)
+	if err != nil {
+		t.Fatal(err)
+	}
+	client := &http.Client{Transport: &http.Transport{Proxy: http.ProxyURL(proxyURL)}}
+
+	out, err := New(WithHTTPClient(client)).Execute(context.Background(), FetchInput{URL: "http://example.invalid/page"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if proxied != "http://example.invalid/page" {
+		t.Errorf("proxy saw %q, want the absolute target URL", proxied)
+	}
+	if !strings.Contains(out, "Hello via proxy") {
+		t.Errorf("got %q, want proxied content", out)
+	}
+}
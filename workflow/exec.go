@@ -246,6 +246,7 @@ func workflowStepsToTraces(order []string, results map[string]StepResult) []core
 		}
 		if sr.Error != nil {
 			trace.Output = truncateStr(sr.Error.Error(), 500)
+			trace.IsError = true
 		}
 		traces = append(traces, trace)
 	}